@@ -1,3 +1,28 @@
+use std::fs;
+use std::path::Path;
+
 fn main() {
+    // Exposed to get_app_version
+    println!(
+        "cargo:rustc-env=QUERY_BUILD_DATE={}",
+        chrono::Utc::now().format("%Y-%m-%d")
+    );
+
+    // Re-run, refreshing the date, whenever a commit or checkout moves HEAD
+    println!("cargo:rerun-if-changed=build.rs");
+    if let Ok(head) = fs::read_to_string("../.git/HEAD") {
+        println!("cargo:rerun-if-changed=../.git/HEAD");
+        if let Some(reference) = head.strip_prefix("ref: ") {
+            let reference = Path::new("../.git").join(reference.trim());
+            // Branches can live in packed-refs instead of their own file
+            let watched = if reference.exists() {
+                reference
+            } else {
+                Path::new("../.git/packed-refs").to_path_buf()
+            };
+            println!("cargo:rerun-if-changed={}", watched.display());
+        }
+    }
+
     tauri_build::build()
 }
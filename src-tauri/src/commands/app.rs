@@ -0,0 +1,9 @@
+use crate::models::AppVersion;
+
+#[tauri::command]
+pub fn get_app_version() -> AppVersion {
+    AppVersion {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        build_date: option_env!("QUERY_BUILD_DATE").map(|d| d.to_string()),
+    }
+}
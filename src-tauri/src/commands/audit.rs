@@ -0,0 +1,10 @@
+use crate::models::AuditEntry;
+use crate::state::AuditLogger;
+
+#[tauri::command]
+pub fn get_audit_log(
+    limit: u32,
+    audit: tauri::State<'_, AuditLogger>,
+) -> Result<Vec<AuditEntry>, String> {
+    audit.read_recent(limit as usize)
+}
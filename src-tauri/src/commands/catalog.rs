@@ -0,0 +1,1086 @@
+use crate::constants::TYPE_MAP_CACHE_TTL_SECS;
+use crate::models::{
+    ArgumentDefault, CompositeAttribute, CompositeTypeInfo, ConnectionConfig, ConstraintInfo,
+    DomainInfo, EnumColumnUsage, EnumTypeInfo, EventTriggerInfo, FunctionArg, InheritanceRelation,
+    RoutineInfo, SchemaObject, SchemaObjectCount, SchemaVersion, StorageParam,
+    TablespaceAssignment,
+};
+use crate::state::TypeMapCache;
+use crate::utils::{connect_postgres, qualified_name};
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+#[tauri::command]
+pub async fn get_column_data_type_map(
+    config: ConnectionConfig,
+    cache: tauri::State<'_, TypeMapCache>,
+) -> Result<HashMap<u32, String>, String> {
+    // Keyed without the password so it never sits in memory longer than needed
+    let connection_key = format!(
+        "{}@{}:{}/{}",
+        config.username, config.host, config.port, config.database
+    );
+    let ttl = Duration::from_secs(TYPE_MAP_CACHE_TTL_SECS);
+
+    if let Some(type_map) = cache.get(&connection_key, ttl) {
+        return Ok(type_map);
+    }
+
+    let pool = connect_postgres(&config).await?;
+
+    let type_rows = sqlx::query("SELECT oid::int8 AS oid, typname::text AS typname FROM pg_type")
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to fetch types: {}", e))?;
+
+    pool.close().await;
+
+    let mut type_map = HashMap::new();
+    for row in type_rows {
+        let oid: i64 = row
+            .try_get("oid")
+            .map_err(|e| format!("Failed to get type oid: {}", e))?;
+        let type_name: String = row
+            .try_get("typname")
+            .map_err(|e| format!("Failed to get type name: {}", e))?;
+        type_map.insert(oid as u32, type_name);
+    }
+
+    cache.insert(connection_key, type_map.clone());
+
+    Ok(type_map)
+}
+
+#[tauri::command]
+pub async fn get_domain_types(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<DomainInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+    let domains = fetch_domains(&pool, &schema).await;
+    pool.close().await;
+    domains
+}
+
+// Helper function to fetch domain types and their CHECK constraints for a schema
+pub(crate) async fn fetch_domains(pool: &PgPool, schema: &str) -> Result<Vec<DomainInfo>, String> {
+    let domain_rows = sqlx::query(
+        "SELECT
+            d.domain_name::text AS domain_name,
+            format_type(t.typbasetype, t.typtypmod) AS base_type,
+            t.typnotnull AS not_null,
+            d.domain_default::text AS domain_default,
+            ARRAY(
+                SELECT 'CHECK (' || cc.check_clause || ')'
+                FROM information_schema.domain_constraints dc
+                JOIN information_schema.check_constraints cc
+                  ON cc.constraint_schema = dc.constraint_schema
+                 AND cc.constraint_name = dc.constraint_name
+                WHERE dc.domain_schema = d.domain_schema
+                  AND dc.domain_name = d.domain_name
+                  AND cc.check_clause NOT LIKE '%IS NOT NULL'
+                ORDER BY dc.constraint_name
+            ) AS constraints
+         FROM information_schema.domains d
+         JOIN pg_namespace n ON n.nspname = d.domain_schema
+         JOIN pg_type t ON t.typname = d.domain_name AND t.typnamespace = n.oid
+         WHERE d.domain_schema = $1
+         ORDER BY d.domain_name",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch domains: {}", e))?;
+
+    let mut domains = Vec::new();
+    for row in domain_rows {
+        domains.push(DomainInfo {
+            domain_name: row
+                .try_get("domain_name")
+                .map_err(|e| format!("Failed to get domain name: {}", e))?,
+            base_type: row
+                .try_get("base_type")
+                .map_err(|e| format!("Failed to get base type: {}", e))?,
+            not_null: row
+                .try_get("not_null")
+                .map_err(|e| format!("Failed to get not_null: {}", e))?,
+            default: row.try_get("domain_default").ok().flatten(),
+            constraints: row
+                .try_get("constraints")
+                .map_err(|e| format!("Failed to get domain constraints: {}", e))?,
+        });
+    }
+
+    Ok(domains)
+}
+
+pub(crate) async fn fetch_enum_types(
+    pool: &PgPool,
+    schema: &str,
+) -> Result<Vec<EnumTypeInfo>, String> {
+    let enum_rows = sqlx::query(
+        "SELECT
+            t.typname::text AS enum_name,
+            ARRAY(
+                SELECT e.enumlabel::text
+                FROM pg_enum e
+                WHERE e.enumtypid = t.oid
+                ORDER BY e.enumsortorder
+            ) AS enum_values
+         FROM pg_type t
+         JOIN pg_namespace n ON n.oid = t.typnamespace
+         WHERE n.nspname = $1 AND t.typtype = 'e'
+         ORDER BY t.typname",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch enum types: {}", e))?;
+
+    // Partitions and inherited columns follow their parent, so only the parent is listed
+    let column_rows = sqlx::query(
+        "SELECT
+            t.typname::text AS enum_name,
+            cn.nspname::text AS schema_name,
+            c.relname::text AS table_name,
+            a.attname::text AS column_name,
+            a.atttypid = t.typarray AS is_array,
+            pg_get_expr(d.adbin, d.adrelid) AS column_default
+         FROM pg_type t
+         JOIN pg_namespace n ON n.oid = t.typnamespace
+         JOIN pg_attribute a ON a.atttypid IN (t.oid, t.typarray)
+         JOIN pg_class c ON c.oid = a.attrelid
+         JOIN pg_namespace cn ON cn.oid = c.relnamespace
+         LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+         WHERE n.nspname = $1 AND t.typtype = 'e'
+           AND c.relkind IN ('r', 'p') AND NOT c.relispartition
+           AND a.attnum > 0 AND NOT a.attisdropped AND a.attinhcount = 0
+         ORDER BY cn.nspname, c.relname, a.attnum",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch enum columns: {}", e))?;
+
+    let mut columns_by_enum: HashMap<String, Vec<EnumColumnUsage>> = HashMap::new();
+    for row in column_rows {
+        let enum_name: String = row
+            .try_get("enum_name")
+            .map_err(|e| format!("Failed to get enum name: {}", e))?;
+        columns_by_enum
+            .entry(enum_name)
+            .or_default()
+            .push(EnumColumnUsage {
+                schema_name: row.try_get("schema_name").unwrap_or_default(),
+                table_name: row.try_get("table_name").unwrap_or_default(),
+                column_name: row.try_get("column_name").unwrap_or_default(),
+                is_array: row.try_get("is_array").unwrap_or(false),
+                column_default: row.try_get("column_default").ok().flatten(),
+            });
+    }
+
+    let mut enums = Vec::new();
+    for row in enum_rows {
+        let enum_name: String = row
+            .try_get("enum_name")
+            .map_err(|e| format!("Failed to get enum name: {}", e))?;
+        enums.push(EnumTypeInfo {
+            columns: columns_by_enum.remove(&enum_name).unwrap_or_default(),
+            enum_name,
+            values: row
+                .try_get("enum_values")
+                .map_err(|e| format!("Failed to get enum values: {}", e))?,
+        });
+    }
+
+    Ok(enums)
+}
+
+#[tauri::command]
+pub async fn get_composite_types(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<CompositeTypeInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+    let composite_types = fetch_composite_types(&pool, &schema).await;
+    pool.close().await;
+    composite_types
+}
+
+// Helper function to fetch standalone composite types (CREATE TYPE ... AS (...)) for a schema
+pub(crate) async fn fetch_composite_types(
+    pool: &PgPool,
+    schema: &str,
+) -> Result<Vec<CompositeTypeInfo>, String> {
+    // Table row types are also composite; relkind = 'c' keeps only standalone types
+    let attribute_rows = sqlx::query(
+        "SELECT
+            t.typname::text AS type_name,
+            n.nspname::text AS schema_name,
+            a.attname::text AS attribute_name,
+            format_type(a.atttypid, a.atttypmod) AS data_type,
+            a.attnum::int4 AS ordinal
+         FROM pg_type t
+         JOIN pg_namespace n ON n.oid = t.typnamespace
+         JOIN pg_class c ON c.oid = t.typrelid
+         JOIN pg_attribute a ON a.attrelid = t.typrelid
+         WHERE t.typtype = 'c'
+           AND c.relkind = 'c'
+           AND n.nspname = $1
+           AND a.attnum > 0
+           AND NOT a.attisdropped
+         ORDER BY t.typname, a.attnum",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch composite types: {}", e))?;
+
+    let mut types_map: BTreeMap<String, CompositeTypeInfo> = BTreeMap::new();
+    for row in attribute_rows {
+        let type_name: String = row
+            .try_get("type_name")
+            .map_err(|e| format!("Failed to get type name: {}", e))?;
+        let schema_name: String = row
+            .try_get("schema_name")
+            .map_err(|e| format!("Failed to get schema name: {}", e))?;
+
+        let attribute = CompositeAttribute {
+            attribute_name: row
+                .try_get("attribute_name")
+                .map_err(|e| format!("Failed to get attribute name: {}", e))?,
+            data_type: row
+                .try_get("data_type")
+                .map_err(|e| format!("Failed to get data type: {}", e))?,
+            ordinal: row
+                .try_get("ordinal")
+                .map_err(|e| format!("Failed to get ordinal: {}", e))?,
+        };
+
+        types_map
+            .entry(type_name.clone())
+            .or_insert_with(|| CompositeTypeInfo {
+                type_name,
+                schema_name,
+                attributes: Vec::new(),
+            })
+            .attributes
+            .push(attribute);
+    }
+
+    Ok(types_map.into_values().collect())
+}
+
+#[tauri::command]
+pub async fn get_function_signatures(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<RoutineInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+    let routines = fetch_routines(&pool, &schema).await;
+    pool.close().await;
+    routines
+}
+
+// Helper function to fetch functions and procedures, one entry per overload
+pub(crate) async fn fetch_routines(pool: &PgPool, schema: &str) -> Result<Vec<RoutineInfo>, String> {
+    let routine_rows = sqlx::query(
+        "SELECT
+            specific_name::text AS specific_name,
+            routine_name,
+            routine_type,
+            routine_definition AS definition,
+            data_type AS return_type,
+            (SELECT CASE p.provolatile
+                        WHEN 'v' THEN 'VOLATILE'
+                        WHEN 's' THEN 'STABLE'
+                        WHEN 'i' THEN 'IMMUTABLE'
+                    END
+             FROM pg_proc p
+             JOIN pg_namespace n ON n.oid = p.pronamespace
+             WHERE n.nspname = routine_schema
+               AND p.proname || '_' || p.oid = specific_name) AS volatility
+         FROM information_schema.routines
+         WHERE routine_schema = $1
+         ORDER BY routine_name, specific_name",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch routines: {}", e))?;
+
+    // Input arguments make up the signature; OUT parameters don't participate in overloading
+    let parameter_rows = sqlx::query(
+        "SELECT
+            specific_name::text AS specific_name,
+            COALESCE(parameter_name::text, '') AS parameter_name,
+            CASE
+                WHEN data_type IN ('USER-DEFINED', 'ARRAY') THEN udt_name::text
+                ELSE data_type::text
+            END AS parameter_type
+         FROM information_schema.parameters
+         WHERE specific_schema = $1
+           AND parameter_mode IN ('IN', 'INOUT', 'VARIADIC')
+         ORDER BY specific_name, ordinal_position",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch routine parameters: {}", e))?;
+
+    let mut parameters_map: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    for row in parameter_rows {
+        let specific_name: String = row
+            .try_get("specific_name")
+            .map_err(|e| format!("Failed to get specific name: {}", e))?;
+        let parameter_name: String = row
+            .try_get("parameter_name")
+            .map_err(|e| format!("Failed to get parameter name: {}", e))?;
+        let parameter_type: String = row
+            .try_get("parameter_type")
+            .map_err(|e| format!("Failed to get parameter type: {}", e))?;
+        parameters_map
+            .entry(specific_name)
+            .or_default()
+            .push((parameter_name, parameter_type));
+    }
+
+    let mut defaults_map: HashMap<String, Vec<ArgumentDefault>> = HashMap::new();
+    for (specific_name, default) in fetch_argument_defaults(pool, schema, None).await? {
+        defaults_map.entry(specific_name).or_default().push(default);
+    }
+
+    let mut routines = Vec::new();
+    for routine_row in routine_rows {
+        let specific_name: String = routine_row
+            .try_get("specific_name")
+            .map_err(|e| format!("Failed to get specific name: {}", e))?;
+        let (argument_names, argument_types) = parameters_map
+            .remove(&specific_name)
+            .unwrap_or_default()
+            .into_iter()
+            .unzip();
+
+        routines.push(RoutineInfo {
+            routine_name: routine_row
+                .try_get("routine_name")
+                .map_err(|e| format!("Failed to get routine name: {}", e))?,
+            routine_type: routine_row
+                .try_get("routine_type")
+                .map_err(|e| format!("Failed to get routine type: {}", e))?,
+            definition: routine_row.try_get("definition").ok(),
+            return_type: routine_row.try_get("return_type").ok(),
+            argument_types,
+            argument_names,
+            volatility: routine_row.try_get("volatility").ok().flatten(),
+            argument_defaults: defaults_map.remove(&specific_name).unwrap_or_default(),
+        });
+    }
+
+    Ok(routines)
+}
+
+/// Argument defaults paired with their routine's specific name, optionally for one routine name.
+async fn fetch_argument_defaults(
+    pool: &PgPool,
+    schema: &str,
+    routine_name: Option<&str>,
+) -> Result<Vec<(String, ArgumentDefault)>, String> {
+    // proargdefaults only holds the trailing defaulted inputs as one expression list;
+    // pg_get_function_arg_default deparses it per argument position
+    let rows = sqlx::query(
+        "SELECT
+            p.proname || '_' || p.oid AS specific_name,
+            a.position::int4 AS position,
+            COALESCE(NULLIF(p.proargnames[a.position], ''), '$' || a.position) AS argument_name,
+            pg_get_function_arg_default(p.oid, a.position) AS default_expression
+         FROM pg_proc p
+         JOIN pg_namespace n ON n.oid = p.pronamespace
+         CROSS JOIN LATERAL generate_series(
+             1, COALESCE(array_length(p.proallargtypes, 1), p.pronargs)
+         ) AS a(position)
+         WHERE n.nspname = $1
+           AND ($2::text IS NULL OR p.proname = $2)
+           AND p.pronargdefaults > 0
+           AND pg_get_function_arg_default(p.oid, a.position) IS NOT NULL
+         ORDER BY p.oid, a.position",
+    )
+    .bind(schema)
+    .bind(routine_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch argument defaults: {}", e))?;
+
+    let mut defaults = Vec::new();
+    for row in rows {
+        let specific_name: String = row
+            .try_get("specific_name")
+            .map_err(|e| format!("Failed to get specific name: {}", e))?;
+        defaults.push((
+            specific_name,
+            ArgumentDefault {
+                position: row
+                    .try_get("position")
+                    .map_err(|e| format!("Failed to get argument position: {}", e))?,
+                argument_name: row
+                    .try_get("argument_name")
+                    .map_err(|e| format!("Failed to get argument name: {}", e))?,
+                default_expression: row
+                    .try_get("default_expression")
+                    .map_err(|e| format!("Failed to get default expression: {}", e))?,
+            },
+        ));
+    }
+
+    Ok(defaults)
+}
+
+#[tauri::command]
+pub async fn get_stored_procedure_source(
+    config: ConnectionConfig,
+    schema: String,
+    routine_name: String,
+) -> Result<String, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // pg_get_functiondef works for every language, unlike information_schema.routines
+    let definitions: Vec<String> = sqlx::query_scalar(
+        "SELECT pg_get_functiondef(p.oid)
+         FROM pg_proc p
+         JOIN pg_namespace n ON n.oid = p.pronamespace
+         WHERE n.nspname = $1
+           AND p.proname = $2
+           AND p.prokind IN ('f', 'p', 'w')
+         ORDER BY p.oid",
+    )
+    .bind(&schema)
+    .bind(&routine_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch routine source: {}", e))?;
+
+    pool.close().await;
+
+    if definitions.is_empty() {
+        return Err(format!("Routine '{}' not found in schema '{}'", routine_name, schema));
+    }
+
+    // Overloads are returned one after another
+    Ok(definitions.join("\n"))
+}
+
+#[tauri::command]
+pub async fn get_index_definition(
+    config: ConnectionConfig,
+    schema: String,
+    index_name: String,
+) -> Result<String, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let definition: Option<String> = sqlx::query_scalar(
+        "SELECT pg_get_indexdef(i.indexrelid)
+         FROM pg_index i
+         JOIN pg_class c ON c.oid = i.indexrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE c.relname = $1
+           AND n.nspname = $2",
+    )
+    .bind(&index_name)
+    .bind(&schema)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch index definition: {}", e))?;
+
+    pool.close().await;
+
+    definition.ok_or_else(|| format!("Index '{}' not found in schema '{}'", index_name, schema))
+}
+
+#[tauri::command]
+pub async fn get_constraint_definition(
+    config: ConnectionConfig,
+    schema: String,
+    constraint_name: String,
+) -> Result<String, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Constraint names are only unique per table, so take the oldest match
+    let definition: Option<String> = sqlx::query_scalar(
+        "SELECT pg_get_constraintdef(oid)
+         FROM pg_constraint
+         WHERE conname = $1
+           AND connamespace = (SELECT oid FROM pg_namespace WHERE nspname = $2)
+         ORDER BY oid
+         LIMIT 1",
+    )
+    .bind(&constraint_name)
+    .bind(&schema)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch constraint definition: {}", e))?;
+
+    pool.close().await;
+
+    definition.ok_or_else(|| {
+        format!(
+            "Constraint '{}' not found in schema '{}'",
+            constraint_name, schema
+        )
+    })
+}
+
+#[tauri::command]
+pub async fn get_plpgsql_function_args(
+    config: ConnectionConfig,
+    schema: String,
+    function_name: String,
+) -> Result<Vec<FunctionArg>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // proallargtypes/proargmodes are NULL when every argument is IN, and unlike
+    // information_schema.parameters they include OUT and TABLE columns
+    let arg_rows = sqlx::query(
+        "SELECT
+            COALESCE(NULLIF(a.arg_name, ''), '$' || a.position) AS name,
+            format_type(a.arg_type, NULL) AS arg_type,
+            CASE COALESCE(a.arg_mode, 'i')
+                WHEN 'i' THEN 'IN'
+                WHEN 'o' THEN 'OUT'
+                WHEN 'b' THEN 'INOUT'
+                WHEN 'v' THEN 'VARIADIC'
+                WHEN 't' THEN 'TABLE'
+            END AS mode,
+            pg_get_function_arg_default(p.oid, a.position::int) AS default_value
+         FROM pg_proc p
+         JOIN pg_namespace n ON n.oid = p.pronamespace
+         CROSS JOIN LATERAL unnest(
+             COALESCE(p.proallargtypes, p.proargtypes::oid[]),
+             p.proargmodes::text[],
+             p.proargnames
+         ) WITH ORDINALITY AS a(arg_type, arg_mode, arg_name, position)
+         WHERE n.nspname = $1
+           AND p.proname = $2
+           AND p.prokind IN ('f', 'p', 'w')
+         ORDER BY p.oid, a.position",
+    )
+    .bind(&schema)
+    .bind(&function_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch function arguments: {}", e))?;
+
+    pool.close().await;
+
+    // Overloads are returned one after another
+    let mut args = Vec::new();
+    for row in arg_rows {
+        args.push(FunctionArg {
+            name: row
+                .try_get("name")
+                .map_err(|e| format!("Failed to get argument name: {}", e))?,
+            type_: row
+                .try_get("arg_type")
+                .map_err(|e| format!("Failed to get argument type: {}", e))?,
+            mode: row
+                .try_get("mode")
+                .map_err(|e| format!("Failed to get argument mode: {}", e))?,
+            default_value: row
+                .try_get("default_value")
+                .map_err(|e| format!("Failed to get default value: {}", e))?,
+        });
+    }
+
+    Ok(args)
+}
+
+#[tauri::command]
+pub async fn get_routine_argument_defaults(
+    config: ConnectionConfig,
+    schema: String,
+    routine_name: String,
+) -> Result<Vec<ArgumentDefault>, String> {
+    let pool = connect_postgres(&config).await?;
+    let defaults = fetch_argument_defaults(&pool, &schema, Some(&routine_name)).await;
+    pool.close().await;
+
+    // Overloads are returned one after another
+    Ok(defaults?.into_iter().map(|(_, default)| default).collect())
+}
+
+#[tauri::command]
+pub async fn get_event_triggers(config: ConnectionConfig) -> Result<Vec<EventTriggerInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+    let event_triggers = fetch_event_triggers(&pool).await;
+    pool.close().await;
+    event_triggers
+}
+
+// Helper function to fetch event triggers, which belong to the database rather than a schema
+pub(crate) async fn fetch_event_triggers(pool: &PgPool) -> Result<Vec<EventTriggerInfo>, String> {
+    let trigger_rows = sqlx::query(
+        "SELECT
+            evtname::text AS trigger_name,
+            evtevent::text AS event,
+            CASE evtenabled
+                WHEN 'O' THEN 'ORIGIN'
+                WHEN 'R' THEN 'REPLICA'
+                WHEN 'A' THEN 'ALWAYS'
+                ELSE 'DISABLED'
+            END AS enabled,
+            evtfoid::regproc::text AS function_name,
+            COALESCE(evttags, '{}') AS tags
+         FROM pg_event_trigger
+         ORDER BY evtname",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch event triggers: {}", e))?;
+
+    let mut event_triggers = Vec::new();
+    for row in trigger_rows {
+        event_triggers.push(EventTriggerInfo {
+            trigger_name: row
+                .try_get("trigger_name")
+                .map_err(|e| format!("Failed to get trigger name: {}", e))?,
+            event: row
+                .try_get("event")
+                .map_err(|e| format!("Failed to get trigger event: {}", e))?,
+            enabled: row
+                .try_get("enabled")
+                .map_err(|e| format!("Failed to get trigger enabled state: {}", e))?,
+            function_name: row
+                .try_get("function_name")
+                .map_err(|e| format!("Failed to get trigger function: {}", e))?,
+            tags: row
+                .try_get("tags")
+                .map_err(|e| format!("Failed to get trigger tags: {}", e))?,
+        });
+    }
+
+    Ok(event_triggers)
+}
+
+#[tauri::command]
+pub async fn get_inheritance_hierarchy(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<InheritanceRelation>, String> {
+    let pool = connect_postgres(&config).await?;
+    let relations = fetch_inheritance(&pool, &schema).await;
+    pool.close().await;
+    relations
+}
+
+// Helper function to fetch parent/child table pairs touching a schema.
+// Tables outside the schema are schema-qualified.
+pub(crate) async fn fetch_inheritance(
+    pool: &PgPool,
+    schema: &str,
+) -> Result<Vec<InheritanceRelation>, String> {
+    let relation_rows = sqlx::query(
+        "SELECT
+            CASE WHEN pn.nspname = $1 THEN p.relname::text
+                 ELSE pn.nspname || '.' || p.relname END AS parent_table,
+            CASE WHEN cn.nspname = $1 THEN c.relname::text
+                 ELSE cn.nspname || '.' || c.relname END AS child_table
+         FROM pg_inherits i
+         JOIN pg_class p ON p.oid = i.inhparent
+         JOIN pg_namespace pn ON pn.oid = p.relnamespace
+         JOIN pg_class c ON c.oid = i.inhrelid
+         JOIN pg_namespace cn ON cn.oid = c.relnamespace
+         WHERE (pn.nspname = $1 OR cn.nspname = $1)
+           AND p.relkind = 'r'
+         ORDER BY parent_table, child_table",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch inheritance hierarchy: {}", e))?;
+
+    let mut relations = Vec::new();
+    for row in relation_rows {
+        relations.push(InheritanceRelation {
+            parent_table: row
+                .try_get("parent_table")
+                .map_err(|e| format!("Failed to get parent table: {}", e))?,
+            child_table: row
+                .try_get("child_table")
+                .map_err(|e| format!("Failed to get child table: {}", e))?,
+        });
+    }
+
+    Ok(relations)
+}
+
+#[tauri::command]
+pub async fn get_schema_object_count(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<SchemaObjectCount, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Catalog-only counts, so this stays fast no matter how much data the schema holds
+    let row = sqlx::query(
+        "SELECT
+            (SELECT COUNT(*) FROM information_schema.tables
+             WHERE table_schema = $1 AND table_type = 'BASE TABLE')::int4 AS tables,
+            (SELECT COUNT(*) FROM information_schema.views
+             WHERE table_schema = $1)::int4 AS views,
+            (SELECT COUNT(*) FROM information_schema.routines
+             WHERE routine_schema = $1)::int4 AS routines,
+            (SELECT COUNT(*) FROM pg_class c
+             JOIN pg_namespace n ON n.oid = c.relnamespace
+             WHERE n.nspname = $1 AND c.relkind IN ('i', 'I'))::int4 AS indexes,
+            (SELECT COUNT(*) FROM pg_class c
+             JOIN pg_namespace n ON n.oid = c.relnamespace
+             WHERE n.nspname = $1 AND c.relkind = 'S')::int4 AS sequences",
+    )
+    .bind(&schema)
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("Failed to count schema objects: {}", e))?;
+
+    pool.close().await;
+
+    let count = |column: &str| -> Result<u32, String> {
+        row.try_get::<i32, _>(column)
+            .map(|n| n as u32)
+            .map_err(|e| format!("Failed to get {} count: {}", column, e))
+    };
+
+    Ok(SchemaObjectCount {
+        tables: count("tables")?,
+        views: count("views")?,
+        routines: count("routines")?,
+        indexes: count("indexes")?,
+        sequences: count("sequences")?,
+    })
+}
+
+#[tauri::command]
+pub async fn get_all_schema_objects(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<SchemaObject>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Overloaded routines collapse to a single entry per name. routine_type is NULL
+    // for aggregates and window functions, which are listed as functions.
+    let rows = sqlx::query(
+        "SELECT name, object_type, schema FROM (
+            SELECT table_name::text AS name, 'table' AS object_type, table_schema::text AS schema
+            FROM information_schema.tables
+            WHERE table_schema = $1 AND table_type = 'BASE TABLE'
+            UNION ALL
+            SELECT table_name::text, 'view', table_schema::text
+            FROM information_schema.views
+            WHERE table_schema = $1
+            UNION ALL
+            SELECT DISTINCT routine_name::text, COALESCE(lower(routine_type), 'function'),
+                   routine_schema::text
+            FROM information_schema.routines
+            WHERE routine_schema = $1
+            UNION ALL
+            SELECT c.relname::text,
+                   CASE c.relkind WHEN 'S' THEN 'sequence' ELSE 'index' END,
+                   n.nspname::text
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = $1 AND c.relkind IN ('S', 'i', 'I')
+         ) objects
+         ORDER BY lower(name), object_type",
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch schema objects: {}", e))?;
+
+    pool.close().await;
+
+    rows.iter()
+        .map(|row| {
+            Ok(SchemaObject {
+                name: row
+                    .try_get("name")
+                    .map_err(|e| format!("Failed to get object name: {}", e))?,
+                object_type: row
+                    .try_get("object_type")
+                    .map_err(|e| format!("Failed to get object type: {}", e))?,
+                schema: row
+                    .try_get("schema")
+                    .map_err(|e| format!("Failed to get object schema: {}", e))?,
+            })
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub async fn get_table_constraints_summary(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+) -> Result<Vec<ConstraintInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let constraint_rows = sqlx::query(
+        "SELECT
+            con.conname::text AS name,
+            CASE con.contype
+                WHEN 'p' THEN 'PRIMARY KEY'
+                WHEN 'f' THEN 'FOREIGN KEY'
+                WHEN 'u' THEN 'UNIQUE'
+                WHEN 'c' THEN 'CHECK'
+                WHEN 'x' THEN 'EXCLUDE'
+                WHEN 'n' THEN 'NOT NULL'
+                WHEN 't' THEN 'TRIGGER'
+                ELSE con.contype::text
+            END AS constraint_type,
+            COALESCE(
+                ARRAY(
+                    SELECT a.attname::text
+                    FROM unnest(con.conkey) WITH ORDINALITY AS k(attnum, ordinal)
+                    JOIN pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+                    ORDER BY k.ordinal
+                ),
+                ARRAY[]::text[]
+            ) AS columns,
+            pg_get_constraintdef(con.oid) AS definition,
+            con.condeferrable AS is_deferrable,
+            con.convalidated AS is_validated
+         FROM pg_constraint con
+         JOIN pg_class c ON c.oid = con.conrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1 AND c.relname = $2
+         ORDER BY con.contype, con.conname",
+    )
+    .bind(&schema)
+    .bind(&table_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch constraints: {}", e))?;
+
+    pool.close().await;
+
+    let mut constraints = Vec::new();
+    for row in constraint_rows {
+        constraints.push(ConstraintInfo {
+            name: row
+                .try_get("name")
+                .map_err(|e| format!("Failed to get name: {}", e))?,
+            constraint_type: row
+                .try_get("constraint_type")
+                .map_err(|e| format!("Failed to get constraint_type: {}", e))?,
+            columns: row
+                .try_get("columns")
+                .map_err(|e| format!("Failed to get columns: {}", e))?,
+            definition: row
+                .try_get("definition")
+                .map_err(|e| format!("Failed to get definition: {}", e))?,
+            is_deferrable: row
+                .try_get("is_deferrable")
+                .map_err(|e| format!("Failed to get is_deferrable: {}", e))?,
+            is_validated: row
+                .try_get("is_validated")
+                .map_err(|e| format!("Failed to get is_validated: {}", e))?,
+        });
+    }
+
+    Ok(constraints)
+}
+
+#[tauri::command]
+pub async fn get_tablespace_per_table(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<TablespaceAssignment>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // reltablespace is 0 for tables stored in the database's default tablespace
+    let rows = sqlx::query(
+        "SELECT
+            c.relname::text AS table_name,
+            COALESCE(t.spcname::text, 'pg_default') AS tablespace,
+            pg_relation_size(c.oid) AS size_bytes
+         FROM pg_class c
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         LEFT JOIN pg_tablespace t ON t.oid = c.reltablespace
+         WHERE n.nspname = $1 AND c.relkind IN ('r', 'p')
+         ORDER BY c.relname",
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch tablespace assignments: {}", e))?;
+
+    pool.close().await;
+
+    let mut assignments = Vec::new();
+    for row in rows {
+        assignments.push(TablespaceAssignment {
+            table_name: row
+                .try_get("table_name")
+                .map_err(|e| format!("Failed to get table_name: {}", e))?,
+            tablespace: row
+                .try_get("tablespace")
+                .map_err(|e| format!("Failed to get tablespace: {}", e))?,
+            size_bytes: row
+                .try_get("size_bytes")
+                .map_err(|e| format!("Failed to get size_bytes: {}", e))?,
+        });
+    }
+
+    Ok(assignments)
+}
+
+#[tauri::command]
+pub async fn get_table_row_estimates(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<HashMap<String, i64>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Planner estimates; -1 on PostgreSQL 14+ for tables never vacuumed or analyzed
+    let rows = sqlx::query_as::<_, (String, i64)>(
+        "SELECT c.relname::text, c.reltuples::bigint
+         FROM pg_class c
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1 AND c.relkind = 'r'",
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch row estimates: {}", e))?;
+
+    pool.close().await;
+
+    Ok(rows.into_iter().collect())
+}
+
+#[tauri::command]
+pub async fn get_storage_parameters(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+) -> Result<Vec<StorageParam>, String> {
+    let pool = connect_postgres(&config).await?;
+    let params = fetch_storage_parameters(&pool, &schema, &table_name).await;
+    pool.close().await;
+    params
+}
+
+// Helper function to read a table's WITH (...) storage parameters
+pub(crate) async fn fetch_storage_parameters(
+    pool: &PgPool,
+    schema: &str,
+    table_name: &str,
+) -> Result<Vec<StorageParam>, String> {
+    let rows = sqlx::query(
+        "SELECT o.option_name AS name, o.option_value AS value
+         FROM pg_class c
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         CROSS JOIN LATERAL pg_options_to_table(c.reloptions) o
+         WHERE n.nspname = $1 AND c.relname = $2
+         ORDER BY o.option_name",
+    )
+    .bind(schema)
+    .bind(table_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch storage parameters: {}", e))?;
+
+    let mut params = Vec::new();
+    for row in rows {
+        params.push(StorageParam {
+            name: row
+                .try_get("name")
+                .map_err(|e| format!("Failed to get storage parameter name: {}", e))?,
+            value: row
+                .try_get("value")
+                .map_err(|e| format!("Failed to get storage parameter value: {}", e))?,
+        });
+    }
+
+    Ok(params)
+}
+
+#[tauri::command]
+pub async fn get_schema_version(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Option<SchemaVersion>, String> {
+    let pool = connect_postgres(&config).await?;
+    let version = fetch_schema_version(&pool, &schema).await;
+    pool.close().await;
+    version
+}
+
+// Helper function to read the latest migration from Flyway or Liquibase history.
+// `schema_version` is the table name Flyway used before version 5.
+async fn fetch_schema_version(
+    pool: &PgPool,
+    schema: &str,
+) -> Result<Option<SchemaVersion>, String> {
+    for table in ["flyway_schema_history", "schema_version", "databasechangelog"] {
+        let exists: bool = sqlx::query_scalar("SELECT to_regclass($1) IS NOT NULL")
+            .bind(qualified_name(schema, table))
+            .fetch_one(pool)
+            .await
+            .map_err(|e| format!("Failed to check for {}: {}", table, e))?;
+        if !exists {
+            continue;
+        }
+
+        let query = if table == "databasechangelog" {
+            format!(
+                "SELECT id AS version,
+                    COALESCE(description, '') AS description,
+                    dateexecuted::text AS installed_on,
+                    exectype <> 'FAILED' AS success
+                 FROM {}
+                 ORDER BY orderexecuted DESC
+                 LIMIT 1",
+                qualified_name(schema, table)
+            )
+        } else {
+            // Repeatable migrations have no version, so they never define the schema version
+            format!(
+                "SELECT version,
+                    description,
+                    installed_on::text AS installed_on,
+                    success
+                 FROM {}
+                 WHERE version IS NOT NULL
+                 ORDER BY installed_rank DESC
+                 LIMIT 1",
+                qualified_name(schema, table)
+            )
+        };
+
+        let row = sqlx::query(&query)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", table, e))?;
+
+        return match row {
+            Some(row) => Ok(Some(SchemaVersion {
+                version: row
+                    .try_get("version")
+                    .map_err(|e| format!("Failed to get version: {}", e))?,
+                description: row
+                    .try_get("description")
+                    .map_err(|e| format!("Failed to get description: {}", e))?,
+                installed_on: row
+                    .try_get("installed_on")
+                    .map_err(|e| format!("Failed to get installed_on: {}", e))?,
+                success: row
+                    .try_get("success")
+                    .map_err(|e| format!("Failed to get success: {}", e))?,
+            })),
+            None => Ok(None),
+        };
+    }
+
+    Ok(None)
+}
@@ -1,5 +1,14 @@
-use crate::models::ConnectionConfig;
-use crate::utils::SchemaComparison;
+use crate::models::{ConnectionConfig, QueryComparison};
+use crate::state::{AuditLogger, ConnectionPools};
+use crate::utils::{
+    connect_postgres, estimate_migration, generate_csv_report, generate_html_report,
+    qualified_name, summarize_fingerprints, tables_needing_row_counts, uses_transaction_pooling,
+    ComparisonSummary, MigrationEstimate, SchemaComparison, SchemaFingerprints,
+};
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use std::collections::HashMap;
+use tauri::AppHandle;
 
 #[tauri::command]
 pub async fn compare_schemas(
@@ -19,17 +28,269 @@ pub async fn compare_schemas(
         &target_schema,
         source_config.name.clone(),
         target_config.name.clone(),
+        uses_transaction_pooling(&target_config),
     );
 
     Ok(comparison)
 }
 
+/// Counts differences between two schemas without building the full comparison,
+/// so large schemas can show a quick "X changes found" indicator.
+#[tauri::command]
+pub async fn compare_schemas_summary(
+    source_config: ConnectionConfig,
+    target_config: ConnectionConfig,
+    schema: Option<String>,
+) -> Result<ComparisonSummary, String> {
+    let schema_name = schema.unwrap_or_else(|| "public".to_string());
+
+    let source_pool = connect_postgres(&source_config).await?;
+    let source = fetch_schema_fingerprints(&source_pool, &schema_name).await;
+    source_pool.close().await;
+
+    let target_pool = connect_postgres(&target_config).await?;
+    let target = fetch_schema_fingerprints(&target_pool, &schema_name).await;
+    target_pool.close().await;
+
+    Ok(summarize_fingerprints(
+        &source.map_err(|e| format!("Source: {}", e))?,
+        &target.map_err(|e| format!("Target: {}", e))?,
+    ))
+}
+
+// Helper function to hash each object's definition server-side, so only one
+// short row per object crosses the wire
+async fn fetch_schema_fingerprints(
+    pool: &PgPool,
+    schema: &str,
+) -> Result<SchemaFingerprints, String> {
+    let rows = sqlx::query(
+        "SELECT 'table' AS object_type, cols.table_name::text AS name,
+                md5(string_agg(
+                    concat_ws(':', cols.column_name, cols.data_type, cols.is_nullable,
+                              cols.column_default, cols.character_maximum_length,
+                              cols.numeric_precision, cols.numeric_scale, cols.position),
+                    ',' ORDER BY cols.column_name
+                ) || ':' || (SELECT rel.relrowsecurity
+                             FROM pg_class rel
+                             JOIN pg_namespace n ON n.oid = rel.relnamespace
+                             WHERE n.nspname = $1 AND rel.relname = cols.table_name)::text
+                ) AS fingerprint
+         FROM (
+             -- Position in SELECT * order, since attnum keeps gaps from dropped columns
+             SELECT c.*, row_number() OVER (PARTITION BY c.table_name
+                                            ORDER BY c.ordinal_position) AS position
+             FROM information_schema.columns c
+             JOIN information_schema.tables t
+               ON t.table_schema = c.table_schema AND t.table_name = c.table_name
+             WHERE c.table_schema = $1 AND t.table_type = 'BASE TABLE'
+         ) cols
+         GROUP BY cols.table_name
+         UNION ALL
+         SELECT 'index', tablename || '.' || indexname, md5(indexdef)
+         FROM pg_indexes
+         WHERE schemaname = $1
+         UNION ALL
+         SELECT 'foreign_key', rel.relname || '.' || con.conname,
+                md5(pg_get_constraintdef(con.oid))
+         FROM pg_constraint con
+         JOIN pg_class rel ON rel.oid = con.conrelid
+         JOIN pg_namespace n ON n.oid = rel.relnamespace
+         WHERE n.nspname = $1 AND con.contype = 'f'
+         UNION ALL
+         SELECT 'policy', rel.relname || '.' || pol.polname,
+                md5(concat_ws(':', pol.polcmd, pol.polpermissive,
+                              ARRAY(
+                                  SELECT CASE WHEN r = 0 THEN 'public'
+                                              ELSE pg_get_userbyid(r)::text END
+                                  FROM unnest(pol.polroles) AS r
+                              )::text,
+                              pg_get_expr(pol.polqual, pol.polrelid),
+                              pg_get_expr(pol.polwithcheck, pol.polrelid)))
+         FROM pg_policy pol
+         JOIN pg_class rel ON rel.oid = pol.polrelid
+         JOIN pg_namespace n ON n.oid = rel.relnamespace
+         WHERE n.nspname = $1
+         UNION ALL
+         SELECT 'view', table_name::text, md5(COALESCE(view_definition, ''))
+         FROM information_schema.views
+         WHERE table_schema = $1
+         UNION ALL
+         SELECT 'routine', p.proname || '/' || pg_get_function_identity_arguments(p.oid),
+                md5(concat_ws(':', p.prosrc, p.prorettype::regtype::text, p.proargnames::text,
+                              p.provolatile))
+         FROM pg_proc p
+         JOIN pg_namespace n ON n.oid = p.pronamespace
+         WHERE n.nspname = $1 AND p.prokind IN ('f', 'p')
+         UNION ALL
+         SELECT 'domain', t.typname::text,
+                md5(concat_ws(':', format_type(t.typbasetype, t.typtypmod), t.typnotnull,
+                              t.typdefault,
+                              (SELECT string_agg(pg_get_constraintdef(con.oid), ','
+                                                 ORDER BY con.conname)
+                               FROM pg_constraint con WHERE con.contypid = t.oid)))
+         FROM pg_type t
+         JOIN pg_namespace n ON n.oid = t.typnamespace
+         WHERE n.nspname = $1 AND t.typtype = 'd'
+         UNION ALL
+         SELECT 'event_trigger', evtname::text,
+                md5(concat_ws(':', evtevent, evtenabled, evtfoid::regproc::text, evttags::text))
+         FROM pg_event_trigger",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch schema fingerprints: {}", e))?;
+
+    let mut fingerprints = SchemaFingerprints::new();
+    for row in rows {
+        let object_type: String = row
+            .try_get("object_type")
+            .map_err(|e| format!("Failed to get object type: {}", e))?;
+        let name: String = row
+            .try_get("name")
+            .map_err(|e| format!("Failed to get object name: {}", e))?;
+        let fingerprint: String = row
+            .try_get("fingerprint")
+            .map_err(|e| format!("Failed to get fingerprint: {}", e))?;
+        fingerprints.insert((object_type, name), fingerprint);
+    }
+
+    Ok(fingerprints)
+}
+
 #[tauri::command]
 pub fn generate_migration_sql(comparison: SchemaComparison) -> Result<String, String> {
     let migration_script = crate::utils::generate_migration_script(&comparison);
     Ok(migration_script)
 }
 
+#[tauri::command]
+pub fn save_html_report(comparison: SchemaComparison, file_path: String) -> Result<(), String> {
+    let html = generate_html_report(&comparison)?;
+    std::fs::write(&file_path, html)
+        .map_err(|e| format!("Failed to write report to {}: {}", file_path, e))
+}
+
+#[tauri::command]
+pub fn save_csv_report(comparison: SchemaComparison, file_path: String) -> Result<(), String> {
+    std::fs::write(&file_path, generate_csv_report(&comparison))
+        .map_err(|e| format!("Failed to write report to {}: {}", file_path, e))
+}
+
+#[tauri::command]
+pub async fn estimate_migration_duration(
+    source_config: ConnectionConfig,
+    target_config: ConnectionConfig,
+    comparison: SchemaComparison,
+    schema: Option<String>,
+) -> Result<MigrationEstimate, String> {
+    // The migration runs against the target, so that is where row counts matter
+    let pool = connect_postgres(&target_config).await?;
+    let schema_name = schema.unwrap_or_else(|| "public".to_string());
+
+    let mut row_counts = HashMap::new();
+    for table_name in tables_needing_row_counts(&comparison) {
+        let estimate: Option<i64> = sqlx::query_scalar(
+            "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass($1)",
+        )
+        .bind(qualified_name(&schema_name, &table_name))
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| format!("Failed to fetch row count for {}: {}", table_name, e))?;
+
+        if let Some(rows) = estimate {
+            row_counts.insert(table_name, rows);
+        }
+    }
+
+    pool.close().await;
+
+    let mut estimate = estimate_migration(&comparison, &row_counts);
+    estimate.notes.insert(
+        1,
+        format!(
+            "Estimated for applying changes from {} to {}",
+            source_config.name, target_config.name
+        ),
+    );
+
+    Ok(estimate)
+}
+
+#[tauri::command]
+pub async fn compare_query_results(
+    app: AppHandle,
+    source_config: ConnectionConfig,
+    target_config: ConnectionConfig,
+    query: String,
+    audit: tauri::State<'_, AuditLogger>,
+    pools: tauri::State<'_, ConnectionPools>,
+) -> Result<QueryComparison, String> {
+    use crate::commands::{execute_query, is_read_query};
+
+    // Both databases really run the query, so only compare ones that leave them unchanged
+    if !is_read_query(&query) {
+        return Err("Only queries that read data can be compared".to_string());
+    }
+
+    let (source_result, target_result) = tokio::join!(
+        execute_query(
+            app.clone(),
+            source_config,
+            query.clone(),
+            None,
+            audit.clone(),
+            pools.clone()
+        ),
+        execute_query(app, target_config, query, None, audit, pools)
+    );
+    let source_result = source_result.map_err(|e| format!("Source: {}", e))?;
+    let target_result = target_result.map_err(|e| format!("Target: {}", e))?;
+
+    let row_count_source = source_result.row_count;
+    let row_count_target = target_result.row_count;
+    let columns_match = source_result.columns == target_result.columns;
+
+    // Multiset difference so duplicate rows are matched one-for-one
+    let mut target_counts: HashMap<String, usize> = HashMap::new();
+    for row in &target_result.rows {
+        *target_counts.entry(row_key(row)).or_insert(0) += 1;
+    }
+
+    let mut rows_only_in_source = Vec::new();
+    for row in source_result.rows {
+        match target_counts.get_mut(&row_key(&row)) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => rows_only_in_source.push(row),
+        }
+    }
+
+    // Whatever target rows were not matched by a source row
+    let mut rows_only_in_target = Vec::new();
+    for row in target_result.rows {
+        if let Some(count) = target_counts.get_mut(&row_key(&row)) {
+            if *count > 0 {
+                *count -= 1;
+                rows_only_in_target.push(row);
+            }
+        }
+    }
+
+    Ok(QueryComparison {
+        rows_only_in_source,
+        rows_only_in_target,
+        columns_match,
+        row_count_source,
+        row_count_target,
+    })
+}
+
+// Helper function to build a hashable key for a result row
+fn row_key(row: &[serde_json::Value]) -> String {
+    serde_json::to_string(row).unwrap_or_default()
+}
+
 // Helper function to fetch enhanced schema
 async fn fetch_enhanced_schema(
     config: &ConnectionConfig,
@@ -1,21 +1,28 @@
 use crate::models::{
-    ColumnInfo, ConnectionConfig, DatabaseSchema, ForeignKeyInfo, QueryResult, TableInfo,
-    EnhancedColumnInfo, EnhancedDatabaseSchema, EnhancedTableInfo, IndexInfo, RoutineInfo, ViewInfo,
+    ColumnInfo, ColumnResultInfo, ConnectionConfig, DatabaseInfo, DatabaseSchema, ForeignKeyInfo,
+    MaskConfig, QueryResult, SchemaQueryResult, TableInfo, EnhancedColumnInfo,
+    EnhancedDatabaseSchema, EnhancedTableInfo, IndexInfo, ViewInfo,
 };
-use sqlx::postgres::{PgConnectOptions, PgPool};
-use sqlx::{Column, ConnectOptions, Row};
+use crate::commands::{
+    fetch_composite_types, fetch_domains, fetch_enum_types, fetch_event_triggers, fetch_inheritance,
+    fetch_rls_policies, fetch_routines, fetch_storage_parameters,
+};
+use crate::constants::{
+    MAX_TABLE_SAMPLE_PERCENT, MAX_UNIQUE_VALUES_LIMIT, MIN_TABLE_SAMPLE_PERCENT,
+};
+use crate::state::{AuditLogger, ConnectionPools};
+use crate::utils::{
+    connect_postgres, connection_string_preview, pg_connect_options, pg_value_to_json,
+    qualified_name, quote_identifier, split_sql_script, statement_words,
+};
+use sqlx::postgres::{PgConnection, PgPool, PgRow};
+use sqlx::{Column, Executor, Row};
+use std::collections::HashMap;
+use tauri::AppHandle;
 
 #[tauri::command]
 pub async fn test_postgres_connection(config: ConnectionConfig) -> Result<String, String> {
-    let mut options = PgConnectOptions::new()
-        .host(&config.host)
-        .port(config.port)
-        .username(&config.username)
-        .password(&config.password)
-        .database(&config.database);
-
-    // Disable statement logging to prevent password leakage
-    options = options.disable_statement_logging();
+    let options = pg_connect_options(&config);
 
     let pool = PgPool::connect_with(options)
         .await
@@ -31,46 +38,294 @@ pub async fn test_postgres_connection(config: ConnectionConfig) -> Result<String
 
 #[tauri::command]
 pub async fn execute_query(
+    app: AppHandle,
     config: ConnectionConfig,
     query: String,
+    mask_config: Option<MaskConfig>,
+    audit: tauri::State<'_, AuditLogger>,
+    pools: tauri::State<'_, ConnectionPools>,
 ) -> Result<QueryResult, String> {
-    // Read-only mode validation
+    check_read_only_query(&config, &query)?;
+
+    let start = std::time::Instant::now();
+
+    // Runs in the connection's persistent session, which outlives this command
+    let pool = pools.get_or_connect(&app, &config).await?;
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to acquire connection: {}", e))?;
+
+    let (rows, rows_affected) = fetch_with_rows_affected(&mut conn, &query)
+        .await
+        .map_err(|e| format!("Error executing query: {}", e))?;
+
+    let (columns, result_rows) = rows_to_json(&rows, mask_config.as_ref());
+
+    let execution_time_ms = start.elapsed().as_millis();
+    let row_count = result_rows.len();
+
+    // The query already ran, so a failed audit write must not turn it into an error
+    if let Some(rows_affected) = rows_affected {
+        let _ = audit.record(&config, &query, rows_affected);
+    }
+
+    Ok(QueryResult {
+        columns,
+        rows: result_rows,
+        row_count,
+        execution_time_ms,
+    })
+}
+
+#[tauri::command]
+pub async fn get_column_unique_values(
+    app: AppHandle,
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+    column_name: String,
+    limit: u32,
+    pools: tauri::State<'_, ConnectionPools>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let pool = pools.get_or_connect(&app, &config).await?;
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    // Let the server enforce read-only mode as well
     if config.read_only {
-        let trimmed_query = query.trim().to_uppercase();
-        let allowed_commands = ["SELECT", "DESCRIBE", "DESC", "SHOW", "EXPLAIN"];
-        let is_allowed = allowed_commands.iter().any(|cmd| trimmed_query.starts_with(cmd));
-
-        if !is_allowed {
-            return Err(
-                "Read-only mode: Only SELECT, DESCRIBE, DESC, SHOW, and EXPLAIN queries are allowed"
-                    .to_string(),
-            );
-        }
+        sqlx::query("SET TRANSACTION READ ONLY")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to set transaction read-only: {}", e))?;
     }
 
+    // The text form covers types with no JSON mapping, such as enums and dates
+    let column = quote_identifier(&column_name);
+    let rows = sqlx::query(&format!(
+        "SELECT DISTINCT {column}, {column}::text FROM {} ORDER BY 1 LIMIT $1",
+        qualified_name(&schema, &table_name)
+    ))
+    .bind(limit.min(MAX_UNIQUE_VALUES_LIMIT) as i64)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch unique values: {}", e))?;
+
+    let _ = tx.rollback().await;
+
+    Ok(rows
+        .iter()
+        .map(|row| match pg_value_to_json(row, 0) {
+            serde_json::Value::Null => row
+                .try_get::<Option<String>, _>(1)
+                .ok()
+                .flatten()
+                .map(serde_json::Value::String)
+                .unwrap_or(serde_json::Value::Null),
+            value => value,
+        })
+        .collect())
+}
+
+/// Returns a random sample of roughly `percent`% of a table's rows.
+#[tauri::command]
+pub async fn get_table_sample(
+    app: AppHandle,
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+    percent: f32,
+    mask_config: Option<MaskConfig>,
+    pools: tauri::State<'_, ConnectionPools>,
+) -> Result<QueryResult, String> {
     let start = std::time::Instant::now();
+    let percent = percent.clamp(MIN_TABLE_SAMPLE_PERCENT, MAX_TABLE_SAMPLE_PERCENT);
 
-    let mut options = PgConnectOptions::new()
-        .host(&config.host)
-        .port(config.port)
-        .username(&config.username)
-        .password(&config.password)
-        .database(&config.database);
+    let pool = pools.get_or_connect(&app, &config).await?;
 
-    // Disable statement logging to prevent password leakage
-    options = options.disable_statement_logging();
+    // BERNOULLI reads every page but avoids sorting the whole table like ORDER BY random()
+    let rows = sqlx::query(&format!(
+        "SELECT * FROM {} TABLESAMPLE BERNOULLI ($1)",
+        qualified_name(&schema, &table_name)
+    ))
+    .bind(percent)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to sample table: {}", e))?;
 
-    let pool = PgPool::connect_with(options)
+    let (columns, result_rows) = rows_to_json(&rows, mask_config.as_ref());
+
+    Ok(QueryResult {
+        columns,
+        row_count: result_rows.len(),
+        rows: result_rows,
+        execution_time_ms: start.elapsed().as_millis(),
+    })
+}
+
+#[tauri::command]
+pub async fn run_query_in_all_schemas(
+    app: AppHandle,
+    config: ConnectionConfig,
+    query: String,
+    schema_filter: Option<String>,
+    mask_config: Option<MaskConfig>,
+    audit: tauri::State<'_, AuditLogger>,
+    pools: tauri::State<'_, ConnectionPools>,
+) -> Result<HashMap<String, SchemaQueryResult>, String> {
+    check_read_only_query(&config, &query)?;
+
+    let schemas = get_database_schemas(config.clone()).await?;
+
+    let pool = pools.get_or_connect(&app, &config).await?;
+
+    // The filter is a PostgreSQL regular expression, matched server-side
+    let schemas: Vec<String> = sqlx::query_scalar(
+        "SELECT s FROM unnest($1::text[]) AS s WHERE $2::text IS NULL OR s ~ $2",
+    )
+    .bind(&schemas)
+    .bind(&schema_filter)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Invalid schema filter: {}", e))?;
+
+    // Each schema gets its own transaction, so one that fails (e.g. lacks the table)
+    // neither hides nor rolls back the results of the others
+    let mut results = HashMap::new();
+    for schema in schemas {
+        let outcome = match run_query_in_schema(&pool, &schema, &query, mask_config.as_ref()).await
+        {
+            Ok((result, rows_affected)) => {
+                if let Some(rows_affected) = rows_affected {
+                    let _ = audit.record(&config, &query, rows_affected);
+                }
+                SchemaQueryResult {
+                    result: Some(result),
+                    error: None,
+                }
+            }
+            Err(e) => SchemaQueryResult {
+                result: None,
+                error: Some(e),
+            },
+        };
+        results.insert(schema, outcome);
+    }
+
+    Ok(results)
+}
+
+// Helper function to run a query with the schema first on the search_path, committing
+// only if it succeeds
+async fn run_query_in_schema(
+    pool: &PgPool,
+    schema: &str,
+    query: &str,
+    mask_config: Option<&MaskConfig>,
+) -> Result<(QueryResult, Option<u64>), String> {
+    let start = std::time::Instant::now();
+
+    let mut tx = pool
+        .begin()
         .await
-        .map_err(|e| format!("Error connecting to database: {}", e))?;
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
 
-    let rows = sqlx::query(&query)
-        .fetch_all(&pool)
+    // SET LOCAL keeps the search_path from outliving the transaction on a pooled connection;
+    // public stays on the path so extension functions and types still resolve
+    sqlx::query(&format!(
+        "SET LOCAL search_path TO {}, public",
+        quote_identifier(schema)
+    ))
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to set search_path: {}", e))?;
+
+    // Dropping the transaction on error rolls it back
+    let (rows, rows_affected) = fetch_with_rows_affected(&mut tx, query)
         .await
         .map_err(|e| format!("Error executing query: {}", e))?;
 
-    pool.close().await;
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {}", e))?;
 
+    let (columns, result_rows) = rows_to_json(&rows, mask_config);
+    let row_count = result_rows.len();
+
+    Ok((
+        QueryResult {
+            columns,
+            rows: result_rows,
+            row_count,
+            execution_time_ms: start.elapsed().as_millis(),
+        },
+        rows_affected,
+    ))
+}
+
+// Helper function to reject anything but read queries on read-only connections
+pub(crate) fn check_read_only_query(config: &ConnectionConfig, query: &str) -> Result<(), String> {
+    if config.read_only && !is_read_query(query) {
+        return Err(
+            "Read-only mode: Only SELECT, VALUES, TABLE, WITH, DESCRIBE, DESC, SHOW, and EXPLAIN \
+             queries that do not modify data are allowed"
+                .to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+// Helper function to classify a query by the commands it runs. Comments and opening
+// parentheses are skipped, and a WITH query only reads if none of its parts modify data.
+// Anything else counts as a write.
+pub(crate) fn is_read_query(query: &str) -> bool {
+    let statements = split_sql_script(query);
+    !statements.is_empty()
+        && statements.iter().all(|statement| {
+            let words = statement_words(statement);
+            match words.first().map(String::as_str) {
+                Some("SELECT" | "VALUES" | "TABLE" | "DESCRIBE" | "DESC" | "SHOW" | "EXPLAIN") => {
+                    true
+                }
+                // Errs towards a write, e.g. for an outer SELECT ... FOR UPDATE
+                Some("WITH") => !words
+                    .iter()
+                    .any(|word| matches!(word.as_str(), "INSERT" | "UPDATE" | "DELETE" | "MERGE")),
+                _ => false,
+            }
+        })
+}
+
+// Helper function to run a query, also returning the rows it changed unless it only reads.
+// fetch_all() does not report rows affected, so statements without result columns go
+// through execute(); those with RETURNING produce one row per changed row.
+async fn fetch_with_rows_affected(
+    conn: &mut PgConnection,
+    query: &str,
+) -> Result<(Vec<PgRow>, Option<u64>), sqlx::Error> {
+    if is_read_query(query) {
+        return Ok((sqlx::query(query).fetch_all(conn).await?, None));
+    }
+
+    if conn.describe(query).await?.columns().is_empty() {
+        let result = sqlx::query(query).execute(conn).await?;
+        Ok((Vec::new(), Some(result.rows_affected())))
+    } else {
+        let rows = sqlx::query(query).fetch_all(conn).await?;
+        let rows_affected = rows.len() as u64;
+        Ok((rows, Some(rows_affected)))
+    }
+}
+
+// Helper function to convert rows to column names and JSON values.
+// Masking happens here so raw values never reach the frontend.
+fn rows_to_json(
+    rows: &[PgRow],
+    mask_config: Option<&MaskConfig>,
+) -> (Vec<String>, Vec<Vec<serde_json::Value>>) {
     // Extract column names
     let mut columns = Vec::new();
     if let Some(first_row) = rows.first() {
@@ -79,38 +334,112 @@ pub async fn execute_query(
         }
     }
 
+    let masked_columns: Vec<bool> = columns
+        .iter()
+        .map(|name| {
+            mask_config
+                .is_some_and(|mask| mask.columns.iter().any(|c| c.eq_ignore_ascii_case(name)))
+        })
+        .collect();
+
     // Convert rows to JSON
     let mut result_rows = Vec::new();
     for row in rows.iter() {
         let mut result_row = Vec::new();
         for (i, _column) in row.columns().iter().enumerate() {
-            let value = if let Ok(v) = row.try_get::<String, _>(i) {
-                serde_json::json!(v)
-            } else if let Ok(v) = row.try_get::<i32, _>(i) {
-                serde_json::json!(v)
-            } else if let Ok(v) = row.try_get::<i64, _>(i) {
-                serde_json::json!(v)
-            } else if let Ok(v) = row.try_get::<bool, _>(i) {
-                serde_json::json!(v)
-            } else if let Ok(v) = row.try_get::<f64, _>(i) {
-                serde_json::json!(v)
-            } else {
-                serde_json::Value::Null
+            let value = pg_value_to_json(row, i);
+            let value = match mask_config {
+                Some(mask) if masked_columns[i] => mask_value(value, mask.mask_char),
+                _ => value,
             };
             result_row.push(value);
         }
         result_rows.push(result_row);
     }
 
-    let execution_time_ms = start.elapsed().as_millis();
-    let row_count = result_rows.len();
+    (columns, result_rows)
+}
 
-    Ok(QueryResult {
-        columns,
-        rows: result_rows,
-        row_count,
-        execution_time_ms,
-    })
+// Helper function to replace a value with mask characters of the same length.
+// Non-string values are masked by their text form so numeric identifiers are hidden too.
+fn mask_value(value: serde_json::Value, mask_char: char) -> serde_json::Value {
+    let length = match &value {
+        serde_json::Value::Null => return value,
+        serde_json::Value::String(s) => s.chars().count(),
+        other => other.to_string().chars().count(),
+    };
+    serde_json::json!(mask_char.to_string().repeat(length))
+}
+
+/// Describes the columns a query would return without executing it.
+#[tauri::command]
+pub async fn get_query_result_schema(
+    config: ConnectionConfig,
+    query: String,
+) -> Result<Vec<ColumnResultInfo>, String> {
+    let query = query.trim().trim_end_matches(';');
+
+    let pool = connect_postgres(&config).await?;
+
+    // Prepared statements are per-session, so everything runs on one connection
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to acquire connection: {}", e))?;
+
+    sqlx::query(&format!("PREPARE __q AS {}", query))
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let result_types = sqlx::query(
+        "SELECT t.oid, format_type(t.oid, NULL) AS type_name
+         FROM pg_prepared_statements ps
+         CROSS JOIN LATERAL unnest(ps.result_types) WITH ORDINALITY AS r(type_id, ordinal)
+         JOIN pg_type t ON t.oid = r.type_id
+         WHERE ps.name = '__q'
+         ORDER BY r.ordinal",
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| format!("Failed to fetch result types: {}", e));
+
+    // pg_prepared_statements has no column names or nullability; the protocol-level
+    // describe supplies both without running the query either
+    let describe = conn
+        .describe(query)
+        .await
+        .map_err(|e| format!("Failed to describe query: {}", e));
+
+    let _ = sqlx::query("DEALLOCATE __q").execute(&mut *conn).await;
+    drop(conn);
+    pool.close().await;
+
+    let result_types = result_types?;
+    let describe = describe?;
+
+    result_types
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let type_oid: sqlx::postgres::types::Oid = row
+                .try_get("oid")
+                .map_err(|e| format!("Failed to read type oid: {}", e))?;
+            Ok(ColumnResultInfo {
+                name: describe
+                    .columns()
+                    .get(i)
+                    .map(|c| c.name().to_string())
+                    .unwrap_or_default(),
+                type_oid: type_oid.0,
+                type_name: row
+                    .try_get("type_name")
+                    .map_err(|e| format!("Failed to read type name: {}", e))?,
+                // Unknown nullability (e.g. computed expressions) is reported as nullable
+                nullable: describe.nullable(i).unwrap_or(true),
+            })
+        })
+        .collect()
 }
 
 #[tauri::command]
@@ -118,15 +447,7 @@ pub async fn get_database_schema(
     config: ConnectionConfig,
     schema: Option<String>,
 ) -> Result<DatabaseSchema, String> {
-    let mut options = PgConnectOptions::new()
-        .host(&config.host)
-        .port(config.port)
-        .username(&config.username)
-        .password(&config.password)
-        .database(&config.database);
-
-    // Disable statement logging to prevent password leakage
-    options = options.disable_statement_logging();
+    let options = pg_connect_options(&config);
 
     let pool = PgPool::connect_with(options)
         .await
@@ -272,16 +593,56 @@ pub async fn get_database_schema(
 }
 
 #[tauri::command]
-pub async fn get_database_schemas(config: ConnectionConfig) -> Result<Vec<String>, String> {
-    let mut options = PgConnectOptions::new()
-        .host(&config.host)
-        .port(config.port)
-        .username(&config.username)
-        .password(&config.password)
-        .database(&config.database);
+pub fn get_connection_string_preview(config: ConnectionConfig) -> String {
+    connection_string_preview(&config)
+}
+
+/// Builds a connection from the libpq environment variables (PGHOST, PGUSER, ...).
+#[tauri::command]
+pub fn get_connection_from_env() -> Result<ConnectionConfig, String> {
+    let var = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+
+    let missing: Vec<&str> = ["PGHOST", "PGDATABASE", "PGUSER"]
+        .into_iter()
+        .filter(|key| var(key).is_none())
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Missing environment variables: {}", missing.join(", ")));
+    }
 
-    // Disable statement logging to prevent password leakage
-    options = options.disable_statement_logging();
+    let host = var("PGHOST").unwrap_or_default();
+    let database = var("PGDATABASE").unwrap_or_default();
+
+    let port = match var("PGPORT") {
+        Some(port) => port
+            .parse::<u16>()
+            .map_err(|_| format!("Invalid PGPORT: '{}'", port))?,
+        None => 5432,
+    };
+
+    Ok(ConnectionConfig {
+        name: format!("{}@{}", database, host),
+        host,
+        port,
+        database,
+        username: var("PGUSER").unwrap_or_default(),
+        password: var("PGPASSWORD").unwrap_or_default(),
+        read_only: false,
+        schema: var("PGSCHEMA"),
+        pool_mode: None,
+    })
+}
+
+/// Lists the pg_catalog tables with their columns, via the basic schema query.
+#[tauri::command]
+pub async fn get_system_catalog_tables(config: ConnectionConfig) -> Result<Vec<TableInfo>, String> {
+    let catalog = get_database_schema(config, Some("pg_catalog".to_string())).await?;
+    Ok(catalog.tables)
+}
+
+#[tauri::command]
+pub async fn get_database_schemas(config: ConnectionConfig) -> Result<Vec<String>, String> {
+    let options = pg_connect_options(&config);
 
     let pool = PgPool::connect_with(options)
         .await
@@ -312,20 +673,87 @@ pub async fn get_database_schemas(config: ConnectionConfig) -> Result<Vec<String
     Ok(schemas)
 }
 
+#[tauri::command]
+pub async fn get_default_schema_for_connection(config: ConnectionConfig) -> Result<String, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let search_path: String = sqlx::query_scalar("SHOW search_path")
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| format!("Failed to fetch search_path: {}", e))?;
+
+    pool.close().await;
+
+    // Elements are comma separated and may be double-quoted, e.g. "$user", public
+    let default_schema = search_path
+        .split(',')
+        .map(|element| {
+            let element = element.trim();
+            match element.strip_prefix('"').and_then(|e| e.strip_suffix('"')) {
+                Some(quoted) => quoted.replace("\"\"", "\""),
+                None => element.to_string(),
+            }
+        })
+        .find(|element| !element.is_empty() && element != "$user")
+        .unwrap_or_else(|| "public".to_string());
+
+    Ok(default_schema)
+}
+
+#[tauri::command]
+pub async fn get_database_list(config: ConnectionConfig) -> Result<Vec<DatabaseInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Size is NULL for databases we are not allowed to connect to
+    let database_rows = sqlx::query(
+        "SELECT
+            d.datname::text AS name,
+            r.rolname::text AS owner,
+            pg_encoding_to_char(d.encoding)::text AS encoding,
+            CASE WHEN has_database_privilege(d.datname, 'CONNECT')
+                 THEN pg_database_size(d.datname)
+            END AS size_bytes,
+            d.datistemplate AS is_template
+         FROM pg_database d
+         JOIN pg_roles r ON r.oid = d.datdba
+         ORDER BY d.datname",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch databases: {}", e))?;
+
+    let mut databases = Vec::new();
+    for row in database_rows {
+        databases.push(DatabaseInfo {
+            name: row
+                .try_get("name")
+                .map_err(|e| format!("Failed to get database name: {}", e))?,
+            owner: row
+                .try_get("owner")
+                .map_err(|e| format!("Failed to get owner: {}", e))?,
+            encoding: row
+                .try_get("encoding")
+                .map_err(|e| format!("Failed to get encoding: {}", e))?,
+            size_bytes: row
+                .try_get("size_bytes")
+                .map_err(|e| format!("Failed to get size: {}", e))?,
+            is_template: row
+                .try_get("is_template")
+                .map_err(|e| format!("Failed to get is_template: {}", e))?,
+        });
+    }
+
+    pool.close().await;
+
+    Ok(databases)
+}
+
 #[tauri::command]
 pub async fn get_enhanced_database_schema(
     config: ConnectionConfig,
     schema: Option<String>,
 ) -> Result<EnhancedDatabaseSchema, String> {
-    let mut options = PgConnectOptions::new()
-        .host(&config.host)
-        .port(config.port)
-        .username(&config.username)
-        .password(&config.password)
-        .database(&config.database);
-
-    // Disable statement logging to prevent password leakage
-    options = options.disable_statement_logging();
+    let options = pg_connect_options(&config);
 
     let pool = PgPool::connect_with(options)
         .await
@@ -346,6 +774,8 @@ pub async fn get_enhanced_database_schema(
     .await
     .map_err(|e| format!("Failed to fetch tables: {}", e))?;
 
+    let inheritance = fetch_inheritance(&pool, &schema_name).await?;
+
     let mut tables = Vec::new();
 
     for table_row in table_rows {
@@ -516,11 +946,42 @@ pub async fn get_enhanced_database_schema(
             });
         }
 
+        let rls_enabled: bool = sqlx::query_scalar(
+            "SELECT c.relrowsecurity
+             FROM pg_class c
+             JOIN pg_namespace n ON n.oid = c.relnamespace
+             WHERE n.nspname = $1
+               AND c.relname = $2",
+        )
+        .bind(&schema_name)
+        .bind(&table_name)
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| format!("Failed to fetch RLS status: {}", e))?;
+
+        let rls_policies = fetch_rls_policies(&pool, &schema_name, Some(&table_name)).await?;
+        let storage_options = fetch_storage_parameters(&pool, &schema_name, &table_name).await?;
+
+        let parent_table = inheritance
+            .iter()
+            .find(|r| r.child_table == table_name)
+            .map(|r| r.parent_table.clone());
+        let child_tables = inheritance
+            .iter()
+            .filter(|r| r.parent_table == table_name)
+            .map(|r| r.child_table.clone())
+            .collect();
+
         tables.push(EnhancedTableInfo {
             table_name,
             columns,
             foreign_keys,
             indexes,
+            rls_enabled,
+            rls_policies,
+            parent_table,
+            child_tables,
+            storage_options,
         });
     }
 
@@ -551,34 +1012,12 @@ pub async fn get_enhanced_database_schema(
     }
 
     // Fetch routines (functions and procedures)
-    let routine_rows = sqlx::query(
-        "SELECT
-            routine_name,
-            routine_type,
-            routine_definition AS definition,
-            data_type AS return_type
-         FROM information_schema.routines
-         WHERE routine_schema = $1
-         ORDER BY routine_name",
-    )
-    .bind(&schema_name)
-    .fetch_all(&pool)
-    .await
-    .map_err(|e| format!("Failed to fetch routines: {}", e))?;
-
-    let mut routines = Vec::new();
-    for routine_row in routine_rows {
-        routines.push(RoutineInfo {
-            routine_name: routine_row
-                .try_get("routine_name")
-                .map_err(|e| format!("Failed to get routine name: {}", e))?,
-            routine_type: routine_row
-                .try_get("routine_type")
-                .map_err(|e| format!("Failed to get routine type: {}", e))?,
-            definition: routine_row.try_get("definition").ok(),
-            return_type: routine_row.try_get("return_type").ok(),
-        });
-    }
+    let routines = fetch_routines(&pool, &schema_name).await?;
+
+    let domains = fetch_domains(&pool, &schema_name).await?;
+    let enums = fetch_enum_types(&pool, &schema_name).await?;
+    let composite_types = fetch_composite_types(&pool, &schema_name).await?;
+    let event_triggers = fetch_event_triggers(&pool).await?;
 
     pool.close().await;
 
@@ -586,6 +1025,10 @@ pub async fn get_enhanced_database_schema(
         tables,
         views,
         routines,
+        domains,
+        enums,
+        composite_types,
+        event_triggers,
     })
 }
 
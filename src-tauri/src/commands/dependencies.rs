@@ -0,0 +1,365 @@
+use crate::models::{
+    ConnectionConfig, DependencyGraph, ImpactAnalysis, ViewDependencies, WarningSeverity,
+};
+use crate::utils::connect_postgres;
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use std::collections::{HashSet, VecDeque};
+
+#[tauri::command]
+pub async fn get_cte_dependency_graph(
+    config: ConnectionConfig,
+    schema: String,
+    view_name: String,
+) -> Result<DependencyGraph, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let Some(root) = find_view_oid(&pool, &schema, &view_name).await? else {
+        pool.close().await;
+        return Err(format!("View '{}' not found in schema '{}'", view_name, schema));
+    };
+
+    let mut nodes = vec![view_name.clone()];
+    let mut edges = Vec::new();
+    let mut visited: HashSet<Oid> = HashSet::from([root]);
+    let mut queue: VecDeque<(Oid, String)> = VecDeque::from([(root, view_name)]);
+
+    // Breadth-first walk over the rewrite rules; visited oids stop cycles
+    while let Some((oid, name)) = queue.pop_front() {
+        let dependency_rows = sqlx::query(
+            "SELECT DISTINCT
+                rc.oid AS ref_oid,
+                CASE WHEN rn.nspname = $2 THEN rc.relname::text
+                     ELSE rn.nspname || '.' || rc.relname END AS ref_name,
+                rc.relkind::text AS ref_kind
+             FROM pg_rewrite r
+             JOIN pg_depend d
+               ON d.classid = 'pg_rewrite'::regclass
+              AND d.objid = r.oid
+              AND d.refclassid = 'pg_class'::regclass
+             JOIN pg_class rc ON rc.oid = d.refobjid
+             JOIN pg_namespace rn ON rn.oid = rc.relnamespace
+             WHERE r.ev_class = $1
+               AND d.refobjid <> $1
+             ORDER BY ref_name",
+        )
+        .bind(oid)
+        .bind(&schema)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to fetch view dependencies: {}", e))?;
+
+        for row in dependency_rows {
+            let ref_oid: Oid = row
+                .try_get("ref_oid")
+                .map_err(|e| format!("Failed to get dependency oid: {}", e))?;
+            let ref_name: String = row
+                .try_get("ref_name")
+                .map_err(|e| format!("Failed to get dependency name: {}", e))?;
+            let ref_kind: String = row
+                .try_get("ref_kind")
+                .map_err(|e| format!("Failed to get dependency kind: {}", e))?;
+
+            edges.push((name.clone(), ref_name.clone()));
+
+            if visited.insert(ref_oid) {
+                nodes.push(ref_name.clone());
+                // Only views carry rewrite rules; tables are leaves
+                if ref_kind == "v" || ref_kind == "m" {
+                    queue.push_back((ref_oid, ref_name));
+                }
+            }
+        }
+    }
+
+    pool.close().await;
+
+    Ok(DependencyGraph { nodes, edges })
+}
+
+#[tauri::command]
+pub async fn get_view_dependencies(
+    config: ConnectionConfig,
+    schema: String,
+    view_name: String,
+) -> Result<ViewDependencies, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let Some(root) = find_view_oid(&pool, &schema, &view_name).await? else {
+        pool.close().await;
+        return Err(format!("View '{}' not found in schema '{}'", view_name, schema));
+    };
+
+    // Both walks are transitive; UNION drops revisited relations so cycles terminate
+    let upstream_rows = sqlx::query(
+        "WITH RECURSIVE upstream(oid) AS (
+            SELECT $1::oid
+            UNION
+            SELECT d.refobjid
+            FROM upstream u
+            JOIN pg_rewrite r ON r.ev_class = u.oid
+            JOIN pg_depend d
+              ON d.classid = 'pg_rewrite'::regclass
+             AND d.objid = r.oid
+             AND d.refclassid = 'pg_class'::regclass
+            WHERE d.refobjid <> u.oid
+         )
+         SELECT
+            CASE WHEN n.nspname = $2 THEN c.relname::text
+                 ELSE n.nspname || '.' || c.relname END AS name,
+            c.relkind IN ('v', 'm') AS is_view
+         FROM upstream u
+         JOIN pg_class c ON c.oid = u.oid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE u.oid <> $1
+         ORDER BY name",
+    )
+    .bind(root)
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch upstream dependencies: {}", e))?;
+
+    let downstream_rows = sqlx::query(
+        "WITH RECURSIVE downstream(oid) AS (
+            SELECT $1::oid
+            UNION
+            SELECT r.ev_class
+            FROM downstream dn
+            JOIN pg_depend d
+              ON d.refclassid = 'pg_class'::regclass
+             AND d.refobjid = dn.oid
+             AND d.classid = 'pg_rewrite'::regclass
+            JOIN pg_rewrite r ON r.oid = d.objid
+            WHERE r.ev_class <> dn.oid
+         )
+         SELECT 'view' AS kind,
+            CASE WHEN n.nspname = $2 THEN c.relname::text
+                 ELSE n.nspname || '.' || c.relname END AS name
+         FROM downstream dn
+         JOIN pg_class c ON c.oid = dn.oid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE dn.oid <> $1
+         UNION
+         -- Routines using a view's row type, or referencing it from a BEGIN ATOMIC body
+         SELECT 'routine',
+            CASE WHEN n.nspname = $2 THEN p.proname::text
+                 ELSE n.nspname || '.' || p.proname END
+         FROM downstream dn
+         JOIN pg_class c ON c.oid = dn.oid
+         JOIN pg_depend d
+           ON d.classid = 'pg_proc'::regclass
+          AND ((d.refclassid = 'pg_class'::regclass AND d.refobjid = c.oid)
+               OR (d.refclassid = 'pg_type'::regclass AND d.refobjid = c.reltype))
+         JOIN pg_proc p ON p.oid = d.objid
+         JOIN pg_namespace n ON n.oid = p.pronamespace
+         ORDER BY kind, name",
+    )
+    .bind(root)
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch downstream dependencies: {}", e))?;
+
+    pool.close().await;
+
+    let mut dependencies = ViewDependencies {
+        depends_on_tables: Vec::new(),
+        depends_on_views: Vec::new(),
+        used_by_views: Vec::new(),
+        used_by_routines: Vec::new(),
+    };
+
+    for row in upstream_rows {
+        let name: String = row
+            .try_get("name")
+            .map_err(|e| format!("Failed to get dependency name: {}", e))?;
+        let is_view: bool = row
+            .try_get("is_view")
+            .map_err(|e| format!("Failed to get dependency kind: {}", e))?;
+        if is_view {
+            dependencies.depends_on_views.push(name);
+        } else {
+            dependencies.depends_on_tables.push(name);
+        }
+    }
+
+    for row in downstream_rows {
+        let name: String = row
+            .try_get("name")
+            .map_err(|e| format!("Failed to get dependent name: {}", e))?;
+        let kind: String = row
+            .try_get("kind")
+            .map_err(|e| format!("Failed to get dependent kind: {}", e))?;
+        if kind == "routine" {
+            dependencies.used_by_routines.push(name);
+        } else {
+            dependencies.used_by_views.push(name);
+        }
+    }
+
+    Ok(dependencies)
+}
+
+#[tauri::command]
+pub async fn get_schema_change_impact_analysis(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+    column_name: String,
+) -> Result<ImpactAnalysis, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let column: Option<(Oid, i16)> = sqlx::query_as(
+        "SELECT c.oid, a.attnum
+         FROM pg_attribute a
+         JOIN pg_class c ON c.oid = a.attrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1
+           AND c.relname = $2
+           AND a.attname = $3
+           AND a.attnum > 0
+           AND NOT a.attisdropped",
+    )
+    .bind(&schema)
+    .bind(&table_name)
+    .bind(&column_name)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("Failed to look up column: {}", e))?;
+
+    let Some((table_oid, attnum)) = column else {
+        pool.close().await;
+        return Err(format!(
+            "Column '{}' not found on table '{}.{}'",
+            column_name, schema, table_name
+        ));
+    };
+
+    // Views referencing the column, plus views built on top of those. Routine
+    // bodies are only tracked in pg_depend for BEGIN ATOMIC, so other languages
+    // fall back to a text search of prosrc for both the table and column name
+    let impact_rows = sqlx::query(
+        "WITH RECURSIVE views(oid) AS (
+            SELECT r.ev_class
+            FROM pg_depend d
+            JOIN pg_rewrite r ON r.oid = d.objid
+            WHERE d.classid = 'pg_rewrite'::regclass
+              AND d.refclassid = 'pg_class'::regclass
+              AND d.refobjid = $1
+              AND d.refobjsubid = $2
+              AND r.ev_class <> $1
+            UNION
+            SELECT r.ev_class
+            FROM views v
+            JOIN pg_depend d
+              ON d.refclassid = 'pg_class'::regclass
+             AND d.refobjid = v.oid
+             AND d.classid = 'pg_rewrite'::regclass
+            JOIN pg_rewrite r ON r.oid = d.objid
+            WHERE r.ev_class <> v.oid
+         )
+         SELECT 'view' AS kind,
+            CASE WHEN n.nspname = $3 THEN c.relname::text
+                 ELSE n.nspname || '.' || c.relname END AS name
+         FROM views v
+         JOIN pg_class c ON c.oid = v.oid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         UNION
+         SELECT 'index',
+            CASE WHEN n.nspname = $3 THEN c.relname::text
+                 ELSE n.nspname || '.' || c.relname END
+         FROM pg_depend d
+         JOIN pg_class c ON c.oid = d.objid AND c.relkind IN ('i', 'I')
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE d.classid = 'pg_class'::regclass
+           AND d.refclassid = 'pg_class'::regclass
+           AND d.refobjid = $1
+           AND d.refobjsubid = $2
+         UNION
+         SELECT 'routine',
+            CASE WHEN n.nspname = $3 THEN p.proname::text
+                 ELSE n.nspname || '.' || p.proname END
+         FROM pg_proc p
+         JOIN pg_namespace n ON n.oid = p.pronamespace
+         WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+           AND (EXISTS (
+                    SELECT 1
+                    FROM pg_depend d
+                    WHERE d.classid = 'pg_proc'::regclass
+                      AND d.objid = p.oid
+                      AND d.refclassid = 'pg_class'::regclass
+                      AND d.refobjid = $1
+                      AND d.refobjsubid = $2
+                )
+                OR (position(lower($5) IN lower(p.prosrc)) > 0
+                    AND position(lower($4) IN lower(p.prosrc)) > 0))
+         ORDER BY kind, name",
+    )
+    .bind(table_oid)
+    .bind(attnum)
+    .bind(&schema)
+    .bind(&table_name)
+    .bind(&column_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch dependent objects: {}", e))?;
+
+    pool.close().await;
+
+    let mut views_affected = Vec::new();
+    let mut routines_affected = Vec::new();
+    let mut indexes_affected = Vec::new();
+    for row in impact_rows {
+        let name: String = row
+            .try_get("name")
+            .map_err(|e| format!("Failed to get dependent name: {}", e))?;
+        let kind: String = row
+            .try_get("kind")
+            .map_err(|e| format!("Failed to get dependent kind: {}", e))?;
+        match kind.as_str() {
+            "view" => views_affected.push(name),
+            "index" => indexes_affected.push(name),
+            _ => routines_affected.push(name),
+        }
+    }
+
+    // Dependent views block ALTER COLUMN ... TYPE outright; routines may break at
+    // runtime and indexes are rebuilt under an exclusive lock
+    let risk_level = if !views_affected.is_empty() {
+        WarningSeverity::High
+    } else if !routines_affected.is_empty() || !indexes_affected.is_empty() {
+        WarningSeverity::Medium
+    } else {
+        WarningSeverity::Low
+    };
+
+    Ok(ImpactAnalysis {
+        views_affected,
+        routines_affected,
+        indexes_affected,
+        risk_level,
+    })
+}
+
+// Helper function to resolve a view or materialized view to its oid
+async fn find_view_oid(
+    pool: &PgPool,
+    schema: &str,
+    view_name: &str,
+) -> Result<Option<Oid>, String> {
+    sqlx::query_scalar(
+        "SELECT c.oid
+         FROM pg_class c
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1
+           AND c.relname = $2
+           AND c.relkind IN ('v', 'm')",
+    )
+    .bind(schema)
+    .bind(view_name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to look up view: {}", e))
+}
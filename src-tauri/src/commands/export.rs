@@ -0,0 +1,326 @@
+use crate::commands::get_enhanced_database_schema;
+use crate::constants::SCHEMA_CACHE_TTL_SECS;
+use crate::models::{ConnectionConfig, ErDiagram, QueryResult, TsOptions};
+use crate::state::SchemaCache;
+use crate::utils::{
+    build_er_diagram, connect_postgres, format_result_as_markdown_table, format_result_as_tsv,
+    qualified_name, render_schema_as_dot, render_schema_as_mermaid,
+    render_schema_as_openapi_components, render_schema_as_sql, render_schema_as_typescript,
+    render_table_as_json_schema, render_table_as_sql, SqlExportCatalog,
+};
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use std::io::ErrorKind;
+use std::process::Command;
+use std::time::Duration;
+
+#[tauri::command]
+pub async fn get_schema_as_dot(
+    config: ConnectionConfig,
+    schema: String,
+    layout: String,
+) -> Result<String, String> {
+    if layout != "LR" && layout != "TB" {
+        return Err(format!("Invalid layout '{}': expected LR or TB", layout));
+    }
+
+    let database_schema = get_enhanced_database_schema(config, Some(schema)).await?;
+
+    Ok(render_schema_as_dot(&database_schema, &layout))
+}
+
+#[tauri::command]
+pub async fn get_schema_as_mermaid(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<String, String> {
+    let database_schema = get_enhanced_database_schema(config, Some(schema)).await?;
+
+    Ok(render_schema_as_mermaid(&database_schema))
+}
+
+#[tauri::command]
+pub async fn get_schema_as_openapi_components(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<serde_json::Value, String> {
+    let database_schema = get_enhanced_database_schema(config, Some(schema)).await?;
+
+    Ok(render_schema_as_openapi_components(&database_schema))
+}
+
+#[tauri::command]
+pub async fn get_schema_as_jsonschema(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+) -> Result<serde_json::Value, String> {
+    let database_schema = get_enhanced_database_schema(config, Some(schema.clone())).await?;
+    let table = database_schema
+        .tables
+        .iter()
+        .find(|t| t.table_name == table_name)
+        .ok_or_else(|| format!("Table '{}' not found in schema '{}'", table_name, schema))?;
+
+    Ok(render_table_as_json_schema(table))
+}
+
+#[tauri::command]
+pub async fn get_schema_as_typescript_interfaces(
+    config: ConnectionConfig,
+    schema: String,
+    options: TsOptions,
+) -> Result<String, String> {
+    if !matches!(options.name_transform.as_str(), "pascal" | "camel" | "none") {
+        return Err(format!(
+            "Invalid name transform '{}': expected pascal, camel or none",
+            options.name_transform
+        ));
+    }
+
+    let database_schema = get_enhanced_database_schema(config, Some(schema)).await?;
+
+    Ok(render_schema_as_typescript(&database_schema, &options))
+}
+
+#[tauri::command]
+pub async fn generate_er_diagram_json(
+    config: ConnectionConfig,
+    schema: String,
+    cache: tauri::State<'_, SchemaCache>,
+) -> Result<ErDiagram, String> {
+    // Keyed without the password, like the type map cache
+    let schema_key = format!(
+        "{}@{}:{}/{}/{}",
+        config.username, config.host, config.port, config.database, schema
+    );
+    let ttl = Duration::from_secs(SCHEMA_CACHE_TTL_SECS);
+
+    let database_schema = match cache.get(&schema_key, ttl) {
+        Some(cached) => cached,
+        None => {
+            let fetched = get_enhanced_database_schema(config, Some(schema)).await?;
+            cache.insert(schema_key, fetched.clone());
+            fetched
+        }
+    };
+
+    Ok(build_er_diagram(&database_schema))
+}
+
+#[tauri::command]
+pub async fn export_schema_to_sql(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<String, String> {
+    let database_schema =
+        get_enhanced_database_schema(config.clone(), Some(schema.clone())).await?;
+
+    let pool = connect_postgres(&config).await?;
+    let catalog = fetch_sql_export_catalog(&pool, &schema).await;
+    pool.close().await;
+
+    Ok(render_schema_as_sql(&schema, &database_schema, &catalog?))
+}
+
+// Catalog details for the SQL export. Run with only pg_catalog on the search_path so
+// format_type() and the pg_get_*def() functions schema-qualify every name they print.
+async fn fetch_sql_export_catalog(pool: &PgPool, schema: &str) -> Result<SqlExportCatalog, String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+    sqlx::query("SET LOCAL search_path TO pg_catalog")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to set search_path: {}", e))?;
+
+    let mut catalog = SqlExportCatalog::default();
+
+    let column_rows = sqlx::query(
+        "SELECT c.relname::text AS table_name,
+                a.attname::text AS column_name,
+                format_type(a.atttypid, a.atttypmod) AS column_type
+         FROM pg_attribute a
+         JOIN pg_class c ON c.oid = a.attrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1
+           AND c.relkind IN ('r', 'p')
+           AND a.attnum > 0
+           AND NOT a.attisdropped",
+    )
+    .bind(schema)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch column types: {}", e))?;
+
+    for row in column_rows {
+        let table_name: String = row.try_get("table_name").unwrap_or_default();
+        let column_name: String = row.try_get("column_name").unwrap_or_default();
+        let column_type: String = row.try_get("column_type").unwrap_or_default();
+        catalog
+            .column_types
+            .insert((table_name, column_name), column_type);
+    }
+
+    // Identity and extension-owned sequences come with their column or extension
+    let sequence_rows = sqlx::query(
+        "SELECT
+            format('CREATE SEQUENCE IF NOT EXISTS %I.%I AS %s INCREMENT BY %s MINVALUE %s MAXVALUE %s START WITH %s CACHE %s%s',
+                   n.nspname, c.relname, format_type(s.seqtypid, NULL), s.seqincrement,
+                   s.seqmin, s.seqmax, s.seqstart, s.seqcache,
+                   CASE WHEN s.seqcycle THEN ' CYCLE' ELSE '' END) AS definition,
+            (SELECT format('ALTER SEQUENCE %I.%I OWNED BY %I.%I.%I',
+                           n.nspname, c.relname, tn.nspname, t.relname, a.attname)
+             FROM pg_depend d
+             JOIN pg_class t ON t.oid = d.refobjid
+             JOIN pg_namespace tn ON tn.oid = t.relnamespace
+             JOIN pg_attribute a ON a.attrelid = d.refobjid AND a.attnum = d.refobjsubid
+             WHERE d.classid = 'pg_class'::regclass
+               AND d.objid = c.oid
+               AND d.deptype = 'a') AS owned_by
+         FROM pg_sequence s
+         JOIN pg_class c ON c.oid = s.seqrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1
+           AND NOT EXISTS (
+               SELECT 1 FROM pg_depend d
+               WHERE d.classid = 'pg_class'::regclass
+                 AND d.objid = c.oid
+                 AND d.deptype IN ('i', 'e')
+           )
+         ORDER BY c.relname",
+    )
+    .bind(schema)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch sequences: {}", e))?;
+
+    for row in sequence_rows {
+        catalog
+            .sequences
+            .push(row.try_get("definition").unwrap_or_default());
+        if let Ok(Some(owned_by)) = row.try_get::<Option<String>, _>("owned_by") {
+            catalog.sequence_owners.push(owned_by);
+        }
+    }
+
+    // One row per constraint, so multi-column and cross-schema keys come out whole
+    let fk_rows = sqlx::query(
+        "SELECT c.relname::text AS table_name,
+                con.conname::text AS constraint_name,
+                pg_get_constraintdef(con.oid) AS definition
+         FROM pg_constraint con
+         JOIN pg_class c ON c.oid = con.conrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1 AND con.contype = 'f'
+         ORDER BY c.relname, con.conname",
+    )
+    .bind(schema)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch foreign keys: {}", e))?;
+
+    for row in fk_rows {
+        catalog.foreign_keys.push((
+            row.try_get("table_name").unwrap_or_default(),
+            row.try_get("constraint_name").unwrap_or_default(),
+            row.try_get("definition").unwrap_or_default(),
+        ));
+    }
+
+    // Full CREATE OR REPLACE text; extension-owned functions are left to CREATE EXTENSION
+    catalog.function_definitions = sqlx::query_scalar(
+        "SELECT pg_get_functiondef(p.oid)
+         FROM pg_proc p
+         JOIN pg_namespace n ON n.oid = p.pronamespace
+         WHERE n.nspname = $1
+           AND p.prokind IN ('f', 'p')
+           AND NOT EXISTS (
+               SELECT 1 FROM pg_depend d
+               WHERE d.classid = 'pg_proc'::regclass
+                 AND d.objid = p.oid
+                 AND d.deptype = 'e'
+           )
+         ORDER BY p.proname, p.oid",
+    )
+    .bind(schema)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch function definitions: {}", e))?;
+
+    tx.rollback()
+        .await
+        .map_err(|e| format!("Failed to end transaction: {}", e))?;
+
+    Ok(catalog)
+}
+
+#[tauri::command]
+pub async fn get_create_table_ddl(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+) -> Result<String, String> {
+    // pg_dump reproduces generated columns, complex defaults and inherited constraints
+    let mut pg_dump = Command::new("pg_dump");
+    pg_dump
+        .arg("--schema-only")
+        .arg(format!("--table={}", qualified_name(&schema, &table_name)))
+        .arg("--no-owner")
+        .arg("--no-acl")
+        .arg("--no-password")
+        .arg("-h")
+        .arg(&config.host)
+        .arg("-p")
+        .arg(config.port.to_string())
+        .arg("-U")
+        .arg(&config.username)
+        .arg("-d")
+        .arg(&config.database)
+        .env("PGPASSWORD", &config.password);
+
+    // Waiting on the child blocks, so keep it off the async runtime's worker threads
+    let output = tauri::async_runtime::spawn_blocking(move || pg_dump.output())
+        .await
+        .map_err(|e| format!("Failed to run pg_dump: {}", e))?;
+
+    match output {
+        Ok(output) if output.status.success() => {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        }
+        Ok(output) => Err(format!(
+            "pg_dump failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            // Hand-built fallback when pg_dump is not installed
+            let database_schema =
+                get_enhanced_database_schema(config.clone(), Some(schema.clone())).await?;
+            let table = database_schema
+                .tables
+                .iter()
+                .find(|t| t.table_name == table_name)
+                .ok_or_else(|| {
+                    format!("Table '{}' not found in schema '{}'", table_name, schema)
+                })?;
+            let pool = connect_postgres(&config).await?;
+            let catalog = fetch_sql_export_catalog(&pool, &schema).await;
+            pool.close().await;
+            Ok(render_table_as_sql(&schema, table, &catalog?.column_types)
+                .trim_end()
+                .to_string())
+        }
+        Err(e) => Err(format!("Failed to run pg_dump: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub fn get_result_as_tsv(result: QueryResult) -> String {
+    format_result_as_tsv(result)
+}
+
+#[tauri::command]
+pub fn get_result_as_markdown_table(result: QueryResult) -> String {
+    format_result_as_markdown_table(result)
+}
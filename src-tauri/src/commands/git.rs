@@ -20,6 +20,21 @@ pub struct GitCommit {
     pub timestamp: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiffStat {
+    pub path: String,
+    pub insertions: u32,
+    pub deletions: u32,
+    pub is_binary: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitUserConfig {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub source: String, // origin of the identity, e.g. "file:/home/me/.gitconfig"
+}
+
 #[tauri::command]
 pub fn git_init() -> Result<String, String> {
     let project_path = get_app_dir().map_err(|e| e.to_string())?;
@@ -254,3 +269,319 @@ pub fn git_pull() -> Result<String, String> {
         Ok("Pulled from remote successfully".to_string())
     }
 }
+
+#[tauri::command]
+pub fn git_cherry_pick(commit_hash: String) -> Result<String, String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    if commit_hash.is_empty() || commit_hash.starts_with('-') {
+        return Err("Invalid commit reference".to_string());
+    }
+
+    let output = Command::new("git")
+        .arg("cherry-pick")
+        .arg(&commit_hash)
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to cherry-pick: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // Unmerged paths only exist when the cherry-pick stopped on conflicts
+        let conflicts_output = Command::new("git")
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--diff-filter=U")
+            .current_dir(&project_path)
+            .output()
+            .map_err(|e| format!("Failed to list conflicts: {}", e))?;
+        let conflicts: Vec<String> = String::from_utf8_lossy(&conflicts_output.stdout)
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+
+        if conflicts.is_empty() {
+            return Err(format!("Cherry-pick failed: {}", stderr));
+        }
+
+        let abort_output = Command::new("git")
+            .arg("cherry-pick")
+            .arg("--abort")
+            .current_dir(&project_path)
+            .output()
+            .map_err(|e| format!("Failed to abort cherry-pick: {}", e))?;
+
+        if !abort_output.status.success() {
+            return Err(format!(
+                "Cherry-pick conflicted in {} and could not be aborted: {}",
+                conflicts.join(", "),
+                String::from_utf8_lossy(&abort_output.stderr)
+            ));
+        }
+
+        return Err(format!(
+            "Cherry-pick aborted due to conflicts in: {}",
+            conflicts.join(", ")
+        ));
+    }
+
+    let head_output = Command::new("git")
+        .arg("rev-parse")
+        .arg("HEAD")
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to read new commit: {}", e))?;
+
+    if !head_output.status.success() {
+        let stderr = String::from_utf8_lossy(&head_output.stderr);
+        return Err(format!("Failed to read new commit: {}", stderr));
+    }
+
+    Ok(String::from_utf8_lossy(&head_output.stdout)
+        .trim()
+        .to_string())
+}
+
+#[tauri::command]
+pub fn git_reset(mode: String, commit: String, confirm: bool) -> Result<String, String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    if !matches!(mode.as_str(), "soft" | "mixed" | "hard") {
+        return Err(format!(
+            "Invalid reset mode '{}': expected soft, mixed or hard",
+            mode
+        ));
+    }
+    if commit.is_empty() || commit.starts_with('-') {
+        return Err("Invalid commit reference".to_string());
+    }
+
+    // A hard reset throws away uncommitted work
+    if mode == "hard" && !confirm {
+        return Err("Hard reset discards uncommitted changes and must be confirmed".to_string());
+    }
+
+    let output = Command::new("git")
+        .arg("reset")
+        .arg(format!("--{}", mode))
+        .arg(&commit)
+        .arg("--")
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to reset: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Reset failed: {}", stderr));
+    }
+
+    Ok(format!("Reset ({}) to {}", mode, commit))
+}
+
+#[tauri::command]
+pub fn git_set_user_config(name: String, email: String, global: bool) -> Result<(), String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    for (key, value) in [("user.name", &name), ("user.email", &email)] {
+        if value.trim().is_empty() {
+            return Err(format!("{} cannot be empty", key));
+        }
+
+        let mut command = Command::new("git");
+        command.arg("config");
+        if global {
+            command.arg("--global");
+        }
+        let output = command
+            .arg(key)
+            .arg(value.trim())
+            .current_dir(&project_path)
+            .output()
+            .map_err(|e| format!("Failed to set {}: {}", key, e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to set {}: {}", key, stderr));
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn git_get_user_config() -> Result<GitUserConfig, String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    // Format: origin<TAB>value; git exits with 1 when the key is unset
+    let read_key = |key: &str| -> Result<Option<(String, String)>, String> {
+        let output = Command::new("git")
+            .arg("config")
+            .arg("--show-origin")
+            .arg("--get")
+            .arg(key)
+            .current_dir(&project_path)
+            .output()
+            .map_err(|e| format!("Failed to read {}: {}", key, e))?;
+
+        if !output.status.success() {
+            if output.status.code() == Some(1) {
+                return Ok(None);
+            }
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to read {}: {}", key, stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .trim_end()
+            .split_once('\t')
+            .map(|(origin, value)| (origin.to_string(), value.to_string())))
+    };
+
+    let name = read_key("user.name")?;
+    let email = read_key("user.email")?;
+
+    let source = name
+        .as_ref()
+        .or(email.as_ref())
+        .map(|(origin, _)| origin.clone())
+        .unwrap_or_else(|| "unset".to_string());
+
+    Ok(GitUserConfig {
+        name: name.map(|(_, value)| value),
+        email: email.map(|(_, value)| value),
+        source,
+    })
+}
+
+#[tauri::command]
+pub fn git_get_diff_stat(
+    commit1: Option<String>,
+    commit2: Option<String>,
+) -> Result<Vec<DiffStat>, String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    // Without commits, compare the working tree against HEAD
+    let revisions = match (commit1, commit2) {
+        (None, None) => vec!["HEAD".to_string()],
+        (Some(c1), None) => vec![c1],
+        (None, Some(c2)) => vec!["HEAD".to_string(), c2],
+        (Some(c1), Some(c2)) => vec![c1, c2],
+    };
+    if revisions.iter().any(|r| r.starts_with('-')) {
+        return Err("Invalid commit reference".to_string());
+    }
+
+    let output = Command::new("git")
+        .arg("diff")
+        .arg("--numstat")
+        .args(&revisions)
+        .arg("--")
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to get diff stat: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Git diff failed: {}", stderr));
+    }
+
+    // Format: insertions<TAB>deletions<TAB>path, with "-" counts for binary files
+    let stat_text = String::from_utf8_lossy(&output.stdout);
+    let stats: Vec<DiffStat> = stat_text
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.splitn(3, '\t').collect();
+            if parts.len() != 3 {
+                return None;
+            }
+
+            let is_binary = parts[0] == "-" && parts[1] == "-";
+            Some(DiffStat {
+                path: parts[2].to_string(),
+                insertions: parts[0].parse().unwrap_or(0),
+                deletions: parts[1].parse().unwrap_or(0),
+                is_binary,
+            })
+        })
+        .collect();
+
+    Ok(stats)
+}
+
+#[tauri::command]
+pub fn git_get_untracked_files() -> Result<Vec<String>, String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    // -z keeps paths with special characters unquoted
+    let output = Command::new("git")
+        .arg("ls-files")
+        .arg("--others")
+        .arg("--exclude-standard")
+        .arg("-z")
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to list untracked files: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Git ls-files failed: {}", stderr));
+    }
+
+    let files = String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(|path| path.to_string())
+        .collect();
+
+    Ok(files)
+}
+
+#[tauri::command]
+pub fn git_add_file(relative_path: String) -> Result<(), String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    if relative_path.trim().is_empty() {
+        return Err("File path cannot be empty".to_string());
+    }
+
+    let output = Command::new("git")
+        .arg("add")
+        .arg("--")
+        .arg(&relative_path)
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to stage file: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to stage {}: {}",
+            relative_path,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn git_add_all() -> Result<(), String> {
+    let project_path = get_app_dir().map_err(|e| e.to_string())?;
+
+    let output = Command::new("git")
+        .arg("add")
+        .arg("-A")
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to stage files: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to stage files: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
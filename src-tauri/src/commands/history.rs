@@ -1,6 +1,8 @@
-use crate::models::QueryHistoryEntry;
+use crate::models::{FrequentQuery, QueryHistoryEntry, TimelineBucket};
 use crate::storage::get_history_db;
-use crate::utils::get_app_dir;
+use crate::utils::{get_app_dir, get_history_retention_days_internal};
+use sqlx::sqlite::SqlitePool;
+use std::collections::HashMap;
 
 #[tauri::command]
 pub async fn save_query_to_history(
@@ -26,11 +28,37 @@ pub async fn save_query_to_history(
     .await
     .map_err(|e| format!("Failed to save query: {}", e))?;
 
+    if let Some(days) = get_history_retention_days_internal()? {
+        prune_history(&pool, days).await?;
+    }
+
     pool.close().await;
 
     Ok(())
 }
 
+// Delete history entries older than the retention period
+async fn prune_history(pool: &SqlitePool, retention_days: u32) -> Result<u64, String> {
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(retention_days as i64);
+    prune_history_before(pool, cutoff).await
+}
+
+// Entries executed exactly at the cutoff are kept
+async fn prune_history_before(
+    pool: &SqlitePool,
+    cutoff: chrono::DateTime<chrono::Utc>,
+) -> Result<u64, String> {
+    // executed_at is RFC 3339 with varying offsets, so compare as Julian days (keeps sub-seconds)
+    let result =
+        sqlx::query("DELETE FROM query_history WHERE julianday(executed_at) < julianday(?)")
+            .bind(cutoff.to_rfc3339())
+            .execute(pool)
+            .await
+            .map_err(|e| format!("Failed to prune history: {}", e))?;
+
+    Ok(result.rows_affected())
+}
+
 #[tauri::command]
 pub async fn get_query_history(limit: i64) -> Result<Vec<QueryHistoryEntry>, String> {
     let app_dir = get_app_dir()?;
@@ -82,3 +110,333 @@ pub async fn clear_query_history() -> Result<(), String> {
 
     Ok(())
 }
+
+#[tauri::command]
+pub async fn get_query_history_by_connection(
+) -> Result<HashMap<String, Vec<QueryHistoryEntry>>, String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_history_db(app_dir).await?;
+
+    let rows = sqlx::query_as::<_, (i64, String, String, i64, i64, String)>(
+        "SELECT id, query, connection_name, execution_time_ms, row_count, executed_at
+         FROM query_history
+         ORDER BY executed_at DESC",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch history: {}", e))?;
+
+    pool.close().await;
+
+    // Rows arrive newest first, so each group stays newest first
+    let mut grouped: HashMap<String, Vec<QueryHistoryEntry>> = HashMap::new();
+    for (id, query, connection_name, execution_time_ms, row_count, executed_at) in rows {
+        grouped
+            .entry(connection_name.clone())
+            .or_default()
+            .push(QueryHistoryEntry {
+                id,
+                query,
+                connection_name,
+                execution_time_ms,
+                row_count,
+                executed_at,
+            });
+    }
+
+    Ok(grouped)
+}
+
+#[tauri::command]
+pub async fn clear_history_for_connection(connection_name: String) -> Result<u64, String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_history_db(app_dir).await?;
+
+    let result = sqlx::query("DELETE FROM query_history WHERE connection_name = ?")
+        .bind(&connection_name)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to clear history: {}", e))?;
+
+    pool.close().await;
+
+    Ok(result.rows_affected())
+}
+
+/// Errors are prefixed with "Not found:" or "Database error:" so the frontend
+/// can tell a missing entry apart from a failed lookup
+#[tauri::command]
+pub async fn get_history_entry_by_id(id: i64) -> Result<QueryHistoryEntry, String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_history_db(app_dir).await?;
+
+    let row = sqlx::query_as::<_, (i64, String, String, i64, i64, String)>(
+        "SELECT id, query, connection_name, execution_time_ms, row_count, executed_at
+         FROM query_history
+         WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("Database error: {}", e))?;
+
+    pool.close().await;
+
+    let (id, query, connection_name, execution_time_ms, row_count, executed_at) =
+        row.ok_or_else(|| format!("Not found: history entry {} does not exist", id))?;
+
+    Ok(QueryHistoryEntry {
+        id,
+        query,
+        connection_name,
+        execution_time_ms,
+        row_count,
+        executed_at,
+    })
+}
+
+#[tauri::command]
+pub async fn deduplicate_query_history() -> Result<u64, String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_history_db(app_dir).await?;
+
+    // A row is a duplicate when the entry after it has the same query text, so each
+    // streak of repeats keeps only its most recent entry. Within a streak both row
+    // numbers advance together, so their difference identifies the streak.
+    let result = sqlx::query(
+        "WITH numbered AS (
+            SELECT
+                id,
+                query,
+                executed_at,
+                ROW_NUMBER() OVER (ORDER BY executed_at DESC, id DESC)
+                    - ROW_NUMBER() OVER (
+                        PARTITION BY query
+                        ORDER BY executed_at DESC, id DESC
+                    ) AS streak
+            FROM query_history
+         ),
+         ranked AS (
+            SELECT
+                id,
+                ROW_NUMBER() OVER (
+                    PARTITION BY query, streak
+                    ORDER BY executed_at DESC, id DESC
+                ) AS position
+            FROM numbered
+         )
+         DELETE FROM query_history
+         WHERE id IN (SELECT id FROM ranked WHERE position > 1)",
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to deduplicate history: {}", e))?;
+
+    pool.close().await;
+
+    Ok(result.rows_affected())
+}
+
+#[tauri::command]
+pub async fn get_slowest_queries(
+    limit: u32,
+    connection_name: Option<String>,
+) -> Result<Vec<QueryHistoryEntry>, String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_history_db(app_dir).await?;
+
+    let rows = sqlx::query_as::<_, (i64, String, String, i64, i64, String)>(
+        "SELECT id, query, connection_name, execution_time_ms, row_count, executed_at
+         FROM query_history
+         WHERE ? IS NULL OR connection_name = ?
+         ORDER BY execution_time_ms DESC, id DESC
+         LIMIT ?",
+    )
+    .bind(&connection_name)
+    .bind(&connection_name)
+    .bind(i64::from(limit))
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch slowest queries: {}", e))?;
+
+    pool.close().await;
+
+    let history = rows
+        .into_iter()
+        .map(
+            |(id, query, connection_name, execution_time_ms, row_count, executed_at)| {
+                QueryHistoryEntry {
+                    id,
+                    query,
+                    connection_name,
+                    execution_time_ms,
+                    row_count,
+                    executed_at,
+                }
+            },
+        )
+        .collect();
+
+    Ok(history)
+}
+
+/// Identical query texts grouped together, most executed first
+#[tauri::command]
+pub async fn get_most_frequent_queries(
+    limit: u32,
+    connection_name: Option<String>,
+) -> Result<Vec<FrequentQuery>, String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_history_db(app_dir).await?;
+
+    let rows = sqlx::query_as::<_, (String, i64, f64, String)>(
+        "SELECT query, COUNT(*) AS cnt, AVG(execution_time_ms), MAX(executed_at) AS last_executed
+         FROM query_history
+         WHERE ? IS NULL OR connection_name = ?
+         GROUP BY query
+         ORDER BY cnt DESC, last_executed DESC
+         LIMIT ?",
+    )
+    .bind(&connection_name)
+    .bind(&connection_name)
+    .bind(i64::from(limit))
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch frequent queries: {}", e))?;
+
+    pool.close().await;
+
+    let queries = rows
+        .into_iter()
+        .map(
+            |(query, execution_count, avg_execution_ms, last_executed)| FrequentQuery {
+                query,
+                execution_count: execution_count as u32,
+                avg_execution_ms,
+                last_executed,
+            },
+        )
+        .collect();
+
+    Ok(queries)
+}
+
+/// Query counts per `bucket_minutes` window, oldest first, covering at most the
+/// `limit_buckets` most recent windows that contain any queries
+#[tauri::command]
+pub async fn get_query_history_timeline(
+    bucket_minutes: u32,
+    limit_buckets: u32,
+) -> Result<Vec<TimelineBucket>, String> {
+    if bucket_minutes == 0 || limit_buckets == 0 {
+        return Err("Bucket size and bucket limit must be greater than 0".to_string());
+    }
+
+    let app_dir = get_app_dir()?;
+    let pool = get_history_db(app_dir).await?;
+
+    // strftime('%s') normalizes the RFC 3339 timestamps to UTC epoch seconds
+    let bucket_seconds = i64::from(bucket_minutes) * 60;
+    let rows = sqlx::query_as::<_, (String, i64, f64, i64)>(
+        "SELECT
+            strftime('%Y-%m-%dT%H:%M:%SZ', bucket, 'unixepoch') AS bucket_start,
+            query_count,
+            avg_execution_ms,
+            total_rows
+         FROM (
+            SELECT
+                CAST(strftime('%s', executed_at) AS INTEGER) / ? * ? AS bucket,
+                COUNT(*) AS query_count,
+                AVG(execution_time_ms) AS avg_execution_ms,
+                SUM(MAX(row_count, 0)) AS total_rows
+            FROM query_history
+            GROUP BY bucket
+            ORDER BY bucket DESC
+            LIMIT ?
+         )
+         ORDER BY bucket",
+    )
+    .bind(bucket_seconds)
+    .bind(bucket_seconds)
+    .bind(i64::from(limit_buckets))
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch history timeline: {}", e))?;
+
+    pool.close().await;
+
+    let timeline = rows
+        .into_iter()
+        .map(
+            |(bucket_start, query_count, avg_execution_ms, total_rows)| TimelineBucket {
+                bucket_start,
+                query_count: query_count as u32,
+                avg_execution_ms,
+                total_rows: total_rows as u64,
+            },
+        )
+        .collect();
+
+    Ok(timeline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn prune_history_removes_only_expired_entries() {
+        // A single connection keeps every query on the same in-memory database
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        sqlx::query(
+            "CREATE TABLE query_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                query TEXT NOT NULL,
+                connection_name TEXT NOT NULL,
+                execution_time_ms INTEGER NOT NULL,
+                row_count INTEGER NOT NULL,
+                executed_at TEXT NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(30);
+        let entries = [
+            ("SELECT 'old'", cutoff - chrono::Duration::days(15)),
+            (
+                "SELECT 'just past'",
+                cutoff - chrono::Duration::milliseconds(1),
+            ),
+            ("SELECT 'at cutoff'", cutoff),
+            ("SELECT 'new'", cutoff + chrono::Duration::days(30)),
+        ];
+        for (query, executed_at) in entries {
+            sqlx::query(
+                "INSERT INTO query_history (query, connection_name, execution_time_ms, row_count, executed_at) VALUES (?, 'local', 1, 1, ?)",
+            )
+            .bind(query)
+            .bind(executed_at.to_rfc3339())
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let deleted = prune_history_before(&pool, cutoff).await.unwrap();
+        assert_eq!(deleted, 2);
+
+        let remaining: Vec<String> =
+            sqlx::query_scalar("SELECT query FROM query_history ORDER BY executed_at")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(remaining, vec!["SELECT 'at cutoff'", "SELECT 'new'"]);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::models::{ConnectionConfig, ImportSummary};
+use crate::storage::{load_connections, save_connections, save_password_to_keychain};
+use crate::utils::get_app_dir;
+use std::fs;
+
+#[tauri::command]
+pub fn import_connections_from_pgpass(file_path: String) -> Result<ImportSummary, String> {
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read {}: {}", file_path, e))?;
+
+    let app_dir = get_app_dir()?;
+    let mut connections = load_connections(app_dir.clone())?;
+    let mut summary = ImportSummary::default();
+    let mut passwords = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let fields = split_pgpass_line(trimmed);
+        if fields.len() != 5 {
+            summary.skipped += 1;
+            summary.messages.push(format!(
+                "Line {}: expected 5 fields, found {}",
+                line_number,
+                fields.len()
+            ));
+            continue;
+        }
+
+        // Wildcards match many servers, so there is no single connection to create
+        if fields[..4].iter().any(|f| f == "*") {
+            summary.skipped += 1;
+            summary.messages.push(format!(
+                "Line {}: wildcard entries are not imported",
+                line_number
+            ));
+            continue;
+        }
+
+        let Ok(port) = fields[1].parse::<u16>() else {
+            summary.skipped += 1;
+            summary.messages.push(format!(
+                "Line {}: invalid port '{}'",
+                line_number, fields[1]
+            ));
+            continue;
+        };
+
+        let name = format!("{}@{}", fields[2], fields[0]);
+        if connections.iter().any(|c| c.name == name) {
+            summary.skipped += 1;
+            summary.messages.push(format!(
+                "Line {}: connection '{}' already exists",
+                line_number, name
+            ));
+            continue;
+        }
+
+        passwords.push((line_number, name.clone(), fields[4].clone()));
+        connections.push(ConnectionConfig {
+            name,
+            host: fields[0].clone(),
+            port,
+            database: fields[2].clone(),
+            username: fields[3].clone(),
+            password: String::new(),
+            read_only: false,
+            schema: None,
+            pool_mode: None,
+        });
+        summary.imported += 1;
+    }
+
+    save_connections(connections, app_dir)?;
+
+    // Only once the connections are saved, so a keychain failure cannot orphan passwords
+    for (line_number, name, password) in passwords {
+        if let Err(e) = save_password_to_keychain(&name, &password) {
+            summary.messages.push(format!(
+                "Line {}: connection '{}' was imported without its password: {}",
+                line_number, name, e
+            ));
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Imports PostgreSQL connections from DBeaver 22+ `data-sources.json`
+/// (found under `.dbeaver/` in the DBeaver workspace). DBeaver keeps passwords
+/// encrypted in a separate file, so they are not imported.
+#[tauri::command]
+pub fn import_connections_from_dbeaver(file_path: String) -> Result<ImportSummary, String> {
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read {}: {}", file_path, e))?;
+    let data_sources: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse DBeaver data sources: {}", e))?;
+
+    let entries = data_sources
+        .get("connections")
+        .and_then(|c| c.as_object())
+        .ok_or("No connections found in DBeaver data sources")?;
+
+    let app_dir = get_app_dir()?;
+    let mut connections = load_connections(app_dir.clone())?;
+    let mut summary = ImportSummary::default();
+
+    for (id, entry) in entries {
+        let name = entry
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or(id)
+            .to_string();
+
+        if entry.get("provider").and_then(|p| p.as_str()) != Some("postgresql") {
+            summary.skipped += 1;
+            summary
+                .messages
+                .push(format!("'{}': not a PostgreSQL connection", name));
+            continue;
+        }
+
+        let configuration = entry.get("configuration");
+        let field = |key: &str| -> Option<String> {
+            match configuration?.get(key)? {
+                serde_json::Value::String(s) if !s.is_empty() => Some(s.clone()),
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            }
+        };
+
+        let Some(host) = field("host") else {
+            summary.skipped += 1;
+            summary.messages.push(format!(
+                "'{}': no host (URL-only connections are not supported)",
+                name
+            ));
+            continue;
+        };
+
+        let port = match field("port") {
+            Some(port) => match port.parse::<u16>() {
+                Ok(port) => port,
+                Err(_) => {
+                    summary.skipped += 1;
+                    summary
+                        .messages
+                        .push(format!("'{}': invalid port '{}'", name, port));
+                    continue;
+                }
+            },
+            None => 5432,
+        };
+
+        if connections.iter().any(|c| c.name == name) {
+            summary.skipped += 1;
+            summary
+                .messages
+                .push(format!("'{}': connection already exists", name));
+            continue;
+        }
+
+        connections.push(ConnectionConfig {
+            name,
+            host,
+            port,
+            database: field("database").unwrap_or_else(|| "postgres".to_string()),
+            username: field("user").unwrap_or_default(),
+            password: String::new(),
+            read_only: entry
+                .get("read-only")
+                .and_then(|r| r.as_bool())
+                .unwrap_or(false),
+            schema: None,
+            pool_mode: None,
+        });
+        summary.imported += 1;
+    }
+
+    save_connections(connections, app_dir)?;
+
+    Ok(summary)
+}
+
+// Split a .pgpass line on unescaped ':' and unescape "\:" and "\\"
+fn split_pgpass_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            ':' => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+
+    fields
+}
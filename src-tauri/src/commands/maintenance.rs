@@ -0,0 +1,327 @@
+use crate::constants::SEQUENCE_AT_RISK_PERCENT;
+use crate::models::{
+    AnalyzeResult, ConnectionConfig, MaintenanceResult, MatViewRefreshInfo, SequenceValue,
+    UnusedSequence,
+};
+use crate::utils::{capture_notices, connect_postgres, qualified_name};
+use sqlx::Row;
+
+#[tauri::command]
+pub async fn run_vacuum_analyze(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: Option<String>,
+    full: bool,
+    confirm: bool,
+) -> Result<MaintenanceResult, String> {
+    if config.read_only {
+        return Err("Read-only mode: VACUUM is not allowed".to_string());
+    }
+
+    // VACUUM FULL takes an exclusive lock and rewrites the table
+    if full && !confirm {
+        return Err("VACUUM FULL requires explicit confirmation: pass confirm=true".to_string());
+    }
+
+    let start = std::time::Instant::now();
+    let pool = connect_postgres(&config).await?;
+
+    // Without a table name, vacuum every table in the schema
+    let tables = match &table_name {
+        Some(name) => vec![name.clone()],
+        None => sqlx::query_scalar::<_, String>(
+            "SELECT table_name::text
+             FROM information_schema.tables
+             WHERE table_schema = $1
+             AND table_type = 'BASE TABLE'
+             ORDER BY table_name",
+        )
+        .bind(&schema)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to fetch tables: {}", e))?,
+    };
+
+    let command = if full { "VACUUM FULL ANALYZE" } else { "VACUUM ANALYZE" };
+
+    // VACUUM cannot run inside a transaction block, so use the simple query protocol
+    for table in &tables {
+        let sql = format!("{} {}", command, qualified_name(&schema, table));
+        sqlx::raw_sql(&sql)
+            .execute(&pool)
+            .await
+            .map_err(|e| format!("Failed to vacuum {}.{}: {}", schema, table, e))?;
+    }
+
+    pool.close().await;
+
+    let message = match table_name {
+        Some(name) => format!("{} completed for {}.{}", command, schema, name),
+        None => format!(
+            "{} completed for {} table(s) in schema {}",
+            command,
+            tables.len(),
+            schema
+        ),
+    };
+
+    Ok(MaintenanceResult {
+        duration_ms: start.elapsed().as_millis(),
+        message,
+    })
+}
+
+#[tauri::command]
+pub async fn analyze_table(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: Option<String>,
+    verbose: bool,
+) -> Result<AnalyzeResult, String> {
+    if config.read_only {
+        return Err("Read-only mode: ANALYZE is not allowed".to_string());
+    }
+
+    let start = std::time::Instant::now();
+    let pool = connect_postgres(&config).await?;
+
+    let tables: Vec<String> = sqlx::query_scalar(
+        "SELECT c.relname::text
+         FROM pg_class c
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1
+           AND c.relkind IN ('r', 'p', 'm')
+           AND ($2::text IS NULL OR c.relname = $2)
+         ORDER BY c.relname",
+    )
+    .bind(&schema)
+    .bind(&table_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch tables: {}", e))?;
+
+    if let Some(name) = &table_name {
+        if tables.is_empty() {
+            pool.close().await;
+            return Err(format!("Table '{}' not found in schema '{}'", name, schema));
+        }
+    }
+
+    let command = if verbose { "ANALYZE VERBOSE" } else { "ANALYZE" };
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to acquire connection: {}", e))?;
+
+    // The server decides what may be analyzed: tables it skips, e.g. for lack of
+    // ownership, come back as WARNING notices rather than errors
+    let mut tables_analyzed = Vec::new();
+    let mut warnings = Vec::new();
+    let mut notices = Vec::new();
+    for table in tables {
+        let sql = format!("{} {}", command, qualified_name(&schema, &table));
+        let (result, table_notices) =
+            capture_notices(sqlx::raw_sql(&sql).execute(&mut *conn)).await;
+
+        let mut skipped = false;
+        for notice in table_notices {
+            if notice.is_warning {
+                skipped = true;
+                warnings.push(format!("{}.{}: {}", schema, table, notice.message));
+            } else {
+                notices.push(notice.message);
+            }
+        }
+
+        match result {
+            Ok(_) if !skipped => tables_analyzed.push(table),
+            Ok(_) => {}
+            Err(e) => warnings.push(format!("Failed to analyze {}.{}: {}", schema, table, e)),
+        }
+    }
+
+    drop(conn);
+    pool.close().await;
+
+    Ok(AnalyzeResult {
+        tables_analyzed,
+        duration_ms: start.elapsed().as_millis(),
+        warnings,
+        notices,
+    })
+}
+
+/// Returns each orphaned sequence in the schema with a `DROP SEQUENCE` statement for it
+#[tauri::command]
+pub async fn get_unused_sequences(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<UnusedSequence>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Skip sequences owned by a column (OWNED BY / identity) and ones still
+    // referenced elsewhere, e.g. by a nextval() column default
+    let sequence_names: Vec<String> = sqlx::query_scalar(
+        "SELECT s.sequence_name::text
+         FROM information_schema.sequences s
+         JOIN pg_namespace n ON n.nspname = s.sequence_schema
+         JOIN pg_class c ON c.relname = s.sequence_name AND c.relnamespace = n.oid
+         WHERE s.sequence_schema = $1
+           AND NOT EXISTS (
+               SELECT 1
+               FROM pg_depend d
+               WHERE d.classid = 'pg_class'::regclass
+                 AND d.objid = c.oid
+                 AND d.refclassid = 'pg_class'::regclass
+                 AND d.deptype IN ('a', 'i')
+           )
+           AND NOT EXISTS (
+               SELECT 1
+               FROM pg_depend d
+               WHERE d.refclassid = 'pg_class'::regclass
+                 AND d.refobjid = c.oid
+                 AND d.deptype = 'n'
+           )
+         ORDER BY s.sequence_name",
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch sequences: {}", e))?;
+
+    pool.close().await;
+
+    Ok(sequence_names
+        .into_iter()
+        .map(|sequence_name| UnusedSequence {
+            drop_statement: format!("DROP SEQUENCE {};", qualified_name(&schema, &sequence_name)),
+            sequence_name,
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_sequences_current_values(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<SequenceValue>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // pg_sequences reads last_value without advancing the sequence; it is NULL
+    // until the first nextval(). Descending sequences use up their range downwards.
+    let rows = sqlx::query(
+        "SELECT
+            sequencename::text AS sequence_name,
+            COALESCE(last_value, start_value) AS current_value,
+            max_value,
+            COALESCE(CASE
+                WHEN last_value IS NULL THEN 0
+                WHEN increment_by > 0
+                    THEN (last_value::numeric - min_value)
+                         / NULLIF(max_value::numeric - min_value, 0) * 100
+                ELSE (max_value::numeric - last_value)
+                     / NULLIF(max_value::numeric - min_value, 0) * 100
+            END, 0)::float8 AS percent_used
+         FROM pg_sequences
+         WHERE schemaname = $1
+         ORDER BY percent_used DESC, sequencename",
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch sequences: {}", e))?;
+
+    pool.close().await;
+
+    let mut sequences = Vec::new();
+    for row in rows {
+        let percent_used: f64 = row
+            .try_get("percent_used")
+            .map_err(|e| format!("Failed to get percent used: {}", e))?;
+
+        sequences.push(SequenceValue {
+            sequence_name: row
+                .try_get("sequence_name")
+                .map_err(|e| format!("Failed to get sequence name: {}", e))?,
+            current_value: row
+                .try_get("current_value")
+                .map_err(|e| format!("Failed to get current value: {}", e))?,
+            max_value: row
+                .try_get("max_value")
+                .map_err(|e| format!("Failed to get max value: {}", e))?,
+            percent_used,
+            at_risk: percent_used > SEQUENCE_AT_RISK_PERCENT,
+        });
+    }
+
+    Ok(sequences)
+}
+
+#[tauri::command]
+pub async fn get_materialized_view_refresh_info(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<MatViewRefreshInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let rows = sqlx::query(
+        "SELECT matviewname::text AS view_name, ispopulated, definition
+         FROM pg_matviews
+         WHERE schemaname = $1
+         ORDER BY matviewname",
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch materialized views: {}", e))?;
+
+    pool.close().await;
+
+    let mut views = Vec::new();
+    for row in rows {
+        views.push(MatViewRefreshInfo {
+            view_name: row
+                .try_get("view_name")
+                .map_err(|e| format!("Failed to get view name: {}", e))?,
+            // pg_matviews has no refresh timestamp and the server keeps none elsewhere
+            last_refresh: None,
+            is_populated: row
+                .try_get("ispopulated")
+                .map_err(|e| format!("Failed to get is_populated: {}", e))?,
+            definition: row
+                .try_get("definition")
+                .map_err(|e| format!("Failed to get definition: {}", e))?,
+        });
+    }
+
+    Ok(views)
+}
+
+#[tauri::command]
+pub async fn refresh_materialized_view(
+    config: ConnectionConfig,
+    schema: String,
+    view_name: String,
+    concurrently: bool,
+) -> Result<(), String> {
+    if config.read_only {
+        return Err("Read-only mode: refreshing a materialized view is not allowed".to_string());
+    }
+
+    // CONCURRENTLY keeps the view readable but needs a unique index and a populated view
+    let command = if concurrently {
+        "REFRESH MATERIALIZED VIEW CONCURRENTLY"
+    } else {
+        "REFRESH MATERIALIZED VIEW"
+    };
+
+    let pool = connect_postgres(&config).await?;
+    let result = sqlx::query(&format!("{} {}", command, qualified_name(&schema, &view_name)))
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to refresh {}: {}", view_name, e));
+    pool.close().await;
+    result?;
+
+    Ok(())
+}
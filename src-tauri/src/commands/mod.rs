@@ -1,19 +1,99 @@
+mod app;
+mod audit;
+mod catalog;
 mod comparison;
 mod connection;
+mod dependencies;
+mod export;
 mod git;
 mod history;
+mod import;
+mod maintenance;
+mod notifications;
+mod performance;
 mod saved_queries;
+mod scripts;
+mod schema_tools;
+mod security;
+mod server;
+mod session;
 mod settings;
 
-pub use comparison::{compare_schemas, generate_migration_sql};
-pub use connection::{execute_query, get_database_schema, get_database_schemas, test_postgres_connection, get_enhanced_database_schema};
-pub use git::{check_git_repo, get_git_log, get_git_status, git_commit, git_init, git_pull, git_push};
-pub use history::{clear_query_history, get_query_history, save_query_to_history};
-pub use saved_queries::{delete_saved_query, get_saved_queries, save_query, toggle_pin_query};
+pub use app::get_app_version;
+pub use audit::get_audit_log;
+pub use catalog::{
+    get_all_schema_objects, get_column_data_type_map, get_composite_types,
+    get_constraint_definition, get_domain_types, get_event_triggers, get_function_signatures,
+    get_index_definition, get_inheritance_hierarchy, get_plpgsql_function_args,
+    get_routine_argument_defaults, get_schema_object_count, get_schema_version,
+    get_storage_parameters, get_stored_procedure_source, get_table_constraints_summary,
+    get_table_row_estimates, get_tablespace_per_table,
+};
+pub(crate) use catalog::{
+    fetch_composite_types, fetch_domains, fetch_enum_types, fetch_event_triggers, fetch_inheritance,
+    fetch_routines, fetch_storage_parameters,
+};
+pub use comparison::{
+    compare_query_results, compare_schemas, compare_schemas_summary, estimate_migration_duration,
+    generate_migration_sql, save_csv_report, save_html_report,
+};
+pub use connection::{
+    execute_query, get_column_unique_values, get_connection_from_env, get_connection_string_preview,
+    get_database_list, get_database_schema, get_database_schemas, get_default_schema_for_connection,
+    get_query_result_schema, get_system_catalog_tables, get_table_sample, run_query_in_all_schemas,
+    test_postgres_connection, get_enhanced_database_schema,
+};
+pub(crate) use connection::{check_read_only_query, is_read_query};
+pub use dependencies::{
+    get_cte_dependency_graph, get_schema_change_impact_analysis, get_view_dependencies,
+};
+pub use export::{
+    export_schema_to_sql, generate_er_diagram_json, get_create_table_ddl,
+    get_result_as_markdown_table, get_result_as_tsv, get_schema_as_dot, get_schema_as_mermaid,
+    get_schema_as_jsonschema, get_schema_as_openapi_components, get_schema_as_typescript_interfaces,
+};
+pub use git::{
+    check_git_repo, get_git_log, get_git_status, git_add_all, git_add_file, git_cherry_pick,
+    git_commit, git_get_diff_stat, git_get_untracked_files, git_get_user_config, git_init,
+    git_pull, git_push, git_reset, git_set_user_config,
+};
+pub use history::{
+    clear_history_for_connection, clear_query_history, deduplicate_query_history,
+    get_history_entry_by_id, get_most_frequent_queries, get_query_history,
+    get_query_history_by_connection, get_query_history_timeline, get_slowest_queries,
+    save_query_to_history,
+};
+pub use maintenance::{
+    analyze_table, get_materialized_view_refresh_info, get_sequences_current_values,
+    get_unused_sequences, refresh_materialized_view, run_vacuum_analyze,
+};
+pub use import::{import_connections_from_dbeaver, import_connections_from_pgpass};
+pub use notifications::{unwatch_schema_changes, watch_schema_changes};
+pub use performance::{
+    explain_analyze_structured, find_missing_indexes_on_foreign_keys, get_bloat_estimate,
+    get_column_statistics_histogram, get_index_recommendations, get_pg_stat_statements,
+    get_query_cost_estimate, suggest_query_optimizations, test_query_performance,
+};
+pub use saved_queries::{
+    delete_saved_query, get_saved_queries, get_saved_query_by_id, rename_saved_query,
+    reorder_saved_queries, save_query, save_query_from_history, toggle_pin_query,
+};
+pub use schema_tools::clone_schema;
+pub use scripts::{apply_migration_script, import_sql_file_and_execute};
+pub use security::{
+    check_connection_permissions, get_role_permissions, get_row_level_security_policies,
+};
+pub(crate) use security::fetch_rls_policies;
+pub use server::{
+    get_pg_hba_rules, get_pg_settings, get_pg_wait_events, get_planner_settings,
+    get_publication_tables, get_subscription_status,
+};
+pub use session::{get_connection_pool_stats, get_connection_ssl_info, get_prepared_statements};
 pub use settings::{
     delete_connection_password, get_app_dir, get_auto_connect_enabled, get_connection_password,
     get_current_project_path, get_last_connection, load_connections, load_project_settings,
     save_connection_password, save_connections, set_auto_connect_enabled, set_last_connection,
     set_project_path, get_recent_projects, remove_recent_project, get_vim_mode_enabled,
-    set_vim_mode_enabled,
+    set_vim_mode_enabled, get_history_retention_days, set_history_retention_days,
+    get_health_check_interval_secs, set_health_check_interval_secs,
 };
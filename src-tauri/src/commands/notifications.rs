@@ -0,0 +1,54 @@
+use crate::models::{ConnectionConfig, SchemaChangeNotification};
+use crate::state::{watcher_key, SchemaWatchers};
+use crate::utils::connect_postgres;
+use sqlx::postgres::PgListener;
+use tauri::{AppHandle, Emitter};
+
+#[tauri::command]
+pub async fn watch_schema_changes(
+    app: AppHandle,
+    watchers: tauri::State<'_, SchemaWatchers>,
+    config: ConnectionConfig,
+    channel: String,
+) -> Result<(), String> {
+    let pool = connect_postgres(&config).await?;
+
+    // The listener detaches its own connection from the pool and keeps it open
+    let mut listener = PgListener::connect_with(&pool)
+        .await
+        .map_err(|e| format!("Failed to open listener connection: {}", e))?;
+    listener
+        .listen(&channel)
+        .await
+        .map_err(|e| format!("Failed to LISTEN on {}: {}", channel, e))?;
+
+    let connection_name = config.name.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        // recv() reconnects on its own; an error here means the listener is unusable
+        while let Ok(notification) = listener.recv().await {
+            let _ = app.emit(
+                "schema-change-notification",
+                SchemaChangeNotification {
+                    connection_name: connection_name.clone(),
+                    channel: notification.channel().to_string(),
+                    payload: notification.payload().to_string(),
+                },
+            );
+        }
+    });
+
+    watchers.insert(watcher_key(&config.name, &channel), task)
+}
+
+#[tauri::command]
+pub async fn unwatch_schema_changes(
+    watchers: tauri::State<'_, SchemaWatchers>,
+    config: ConnectionConfig,
+    channel: String,
+) -> Result<(), String> {
+    if !watchers.remove(&watcher_key(&config.name, &channel))? {
+        return Err(format!("Not watching channel '{}' on {}", channel, config.name));
+    }
+
+    Ok(())
+}
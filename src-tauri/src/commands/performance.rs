@@ -0,0 +1,633 @@
+use crate::commands::check_read_only_query;
+use crate::constants::{
+    BLOAT_VACUUM_THRESHOLD, INDEX_RECOMMENDATION_MIN_SEQ_TUP_READ, MAX_BENCHMARK_ITERATIONS,
+};
+use crate::models::{
+    BloatInfo, ColumnHistogram, ConnectionConfig, IndexRecommendation, MissingFkIndex,
+    PerformanceStats, PlanNode, QueryCostEstimate, StatStatement,
+};
+use crate::utils::{
+    connect_postgres, qualified_name, quote_identifier, suggest_optimizations, QuerySuggestion,
+};
+use sqlx::Row;
+
+// Exact measurements via the pgstattuple extension (btree indexes only)
+const PGSTATTUPLE_BLOAT_QUERY: &str = "
+    SELECT
+        c.relname AS table_name,
+        c.relname AS object_name,
+        false AS is_index,
+        s.table_len AS total_bytes,
+        COALESCE((s.dead_tuple_len + s.free_space)::float8 / NULLIF(s.table_len, 0), 0) AS bloat_ratio
+    FROM pg_class c
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+    CROSS JOIN LATERAL pgstattuple(c.oid) s
+    WHERE n.nspname = $1
+      AND c.relkind = 'r'
+    UNION ALL
+    SELECT
+        t.relname AS table_name,
+        i.relname AS object_name,
+        true AS is_index,
+        s.index_size AS total_bytes,
+        CASE WHEN s.avg_leaf_density = 'NaN' THEN 0
+             ELSE GREATEST(0, 1 - s.avg_leaf_density / 100)
+        END AS bloat_ratio
+    FROM pg_index x
+    JOIN pg_class i ON i.oid = x.indexrelid
+    JOIN pg_class t ON t.oid = x.indrelid
+    JOIN pg_namespace n ON n.oid = t.relnamespace
+    JOIN pg_am am ON am.oid = i.relam
+    CROSS JOIN LATERAL pgstatindex(i.oid::regclass) s
+    WHERE n.nspname = $1
+      AND am.amname = 'btree'";
+
+// Heuristic estimate from dead tuple counts and expected index page counts
+const HEURISTIC_BLOAT_QUERY: &str = "
+    SELECT
+        c.relname AS table_name,
+        c.relname AS object_name,
+        false AS is_index,
+        pg_relation_size(c.oid) AS total_bytes,
+        COALESCE(
+            s.n_dead_tup::float8 / NULLIF(s.n_live_tup + s.n_dead_tup, 0),
+            0
+        ) AS bloat_ratio
+    FROM pg_class c
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+    LEFT JOIN pg_stat_user_tables s ON s.relid = c.oid
+    WHERE n.nspname = $1
+      AND c.relkind = 'r'
+    UNION ALL
+    SELECT
+        t.relname AS table_name,
+        i.relname AS object_name,
+        true AS is_index,
+        pg_relation_size(i.oid) AS total_bytes,
+        COALESCE(GREATEST(0, 1 - (
+            CEIL(i.reltuples * (COALESCE(w.width, 8) + 14)
+                 / (current_setting('block_size')::float8 * 0.9)) + 1
+        ) / NULLIF(i.relpages, 0)), 0) AS bloat_ratio
+    FROM pg_index x
+    JOIN pg_class i ON i.oid = x.indexrelid
+    JOIN pg_class t ON t.oid = x.indrelid
+    JOIN pg_namespace n ON n.oid = t.relnamespace
+    LEFT JOIN LATERAL (
+        SELECT SUM(st.avg_width)::float8 AS width
+        FROM pg_attribute a
+        JOIN pg_stats st
+          ON st.schemaname = n.nspname
+         AND st.tablename = t.relname
+         AND st.attname = a.attname
+        WHERE a.attrelid = t.oid
+          AND a.attnum = ANY(x.indkey)
+    ) w ON true
+    WHERE n.nspname = $1";
+
+#[tauri::command]
+pub async fn get_pg_stat_statements(
+    config: ConnectionConfig,
+    limit: u32,
+    order_by: String,
+) -> Result<Vec<StatStatement>, String> {
+    // Only allow known columns since ORDER BY cannot be bound as a parameter
+    let order_column = match order_by.as_str() {
+        "total_time" => "total_exec_time",
+        "mean_time" => "mean_exec_time",
+        "calls" => "calls",
+        "rows" => "rows",
+        _ => {
+            return Err(format!(
+                "Invalid order_by '{}': expected one of total_time, mean_time, calls, rows",
+                order_by
+            ))
+        }
+    };
+
+    let pool = connect_postgres(&config).await?;
+
+    let installed: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'pg_stat_statements')",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("Failed to check for pg_stat_statements: {}", e))?;
+
+    if !installed {
+        pool.close().await;
+        return Err(
+            "The pg_stat_statements extension is not installed. Run CREATE EXTENSION pg_stat_statements; and add it to shared_preload_libraries"
+                .to_string(),
+        );
+    }
+
+    let rows = sqlx::query(&format!(
+        "SELECT
+            query,
+            calls,
+            total_exec_time,
+            mean_exec_time,
+            rows,
+            shared_blks_hit,
+            shared_blks_read
+         FROM pg_stat_statements
+         ORDER BY {} DESC
+         LIMIT $1",
+        order_column
+    ))
+    .bind(limit as i64)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch pg_stat_statements: {}", e))?;
+
+    pool.close().await;
+
+    let mut statements = Vec::new();
+    for row in rows {
+        statements.push(StatStatement {
+            query: row
+                .try_get("query")
+                .map_err(|e| format!("Failed to get query: {}", e))?,
+            calls: row
+                .try_get("calls")
+                .map_err(|e| format!("Failed to get calls: {}", e))?,
+            total_exec_time_ms: row
+                .try_get("total_exec_time")
+                .map_err(|e| format!("Failed to get total_exec_time: {}", e))?,
+            mean_exec_time_ms: row
+                .try_get("mean_exec_time")
+                .map_err(|e| format!("Failed to get mean_exec_time: {}", e))?,
+            rows: row
+                .try_get("rows")
+                .map_err(|e| format!("Failed to get rows: {}", e))?,
+            shared_blks_hit: row
+                .try_get("shared_blks_hit")
+                .map_err(|e| format!("Failed to get shared_blks_hit: {}", e))?,
+            shared_blks_read: row
+                .try_get("shared_blks_read")
+                .map_err(|e| format!("Failed to get shared_blks_read: {}", e))?,
+        });
+    }
+
+    Ok(statements)
+}
+
+#[tauri::command]
+pub async fn get_bloat_estimate(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<BloatInfo>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let has_pgstattuple: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'pgstattuple')",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("Failed to check for pgstattuple: {}", e))?;
+
+    let bloat_query = if has_pgstattuple {
+        PGSTATTUPLE_BLOAT_QUERY
+    } else {
+        HEURISTIC_BLOAT_QUERY
+    };
+
+    let rows = sqlx::query(bloat_query)
+        .bind(&schema)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to estimate bloat: {}", e))?;
+
+    pool.close().await;
+
+    let mut bloat = Vec::new();
+    for row in rows {
+        let total_bytes: i64 = row
+            .try_get("total_bytes")
+            .map_err(|e| format!("Failed to get total_bytes: {}", e))?;
+        let bloat_ratio: f64 = row
+            .try_get("bloat_ratio")
+            .map_err(|e| format!("Failed to get bloat_ratio: {}", e))?;
+
+        bloat.push(BloatInfo {
+            table_name: row
+                .try_get("table_name")
+                .map_err(|e| format!("Failed to get table name: {}", e))?,
+            bloat_ratio,
+            wasted_bytes: (total_bytes as f64 * bloat_ratio) as i64,
+            is_index: row
+                .try_get("is_index")
+                .map_err(|e| format!("Failed to get is_index: {}", e))?,
+            object_name: row
+                .try_get("object_name")
+                .map_err(|e| format!("Failed to get object name: {}", e))?,
+            needs_vacuum: bloat_ratio > BLOAT_VACUUM_THRESHOLD,
+        });
+    }
+
+    // Worst offenders first
+    bloat.sort_by_key(|b| std::cmp::Reverse(b.wasted_bytes));
+
+    Ok(bloat)
+}
+
+#[tauri::command]
+pub async fn get_index_recommendations(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<IndexRecommendation>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Tables scanned sequentially more often than by index are candidates; their
+    // unindexed FK columns are the most likely join/filter columns
+    let rows = sqlx::query(
+        "SELECT DISTINCT
+            s.relname::text AS table_name,
+            a.attname::text AS column_name,
+            s.seq_scan,
+            s.seq_tup_read
+         FROM pg_stat_user_tables s
+         JOIN pg_constraint con
+           ON con.conrelid = s.relid
+          AND con.contype = 'f'
+         JOIN pg_attribute a
+           ON a.attrelid = con.conrelid
+          AND a.attnum = con.conkey[1]
+         WHERE s.schemaname = $1
+           AND s.seq_scan > COALESCE(s.idx_scan, 0)
+           AND s.seq_tup_read > $2
+           AND NOT EXISTS (
+               SELECT 1
+               FROM pg_index i
+               WHERE i.indrelid = s.relid
+                 AND i.indkey[0] = a.attnum
+           )
+         ORDER BY s.seq_tup_read DESC, table_name, column_name",
+    )
+    .bind(&schema)
+    .bind(INDEX_RECOMMENDATION_MIN_SEQ_TUP_READ)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch table scan statistics: {}", e))?;
+
+    pool.close().await;
+
+    let mut recommendations = Vec::new();
+    for row in rows {
+        let table_name: String = row
+            .try_get("table_name")
+            .map_err(|e| format!("Failed to get table name: {}", e))?;
+        let column_name: String = row
+            .try_get("column_name")
+            .map_err(|e| format!("Failed to get column name: {}", e))?;
+
+        let recommendation = format!(
+            "CREATE INDEX {} ON {} ({});",
+            quote_identifier(&format!("idx_{}_{}", table_name, column_name)),
+            qualified_name(&schema, &table_name),
+            quote_identifier(&column_name)
+        );
+
+        recommendations.push(IndexRecommendation {
+            seq_scan_count: row
+                .try_get("seq_scan")
+                .map_err(|e| format!("Failed to get seq_scan: {}", e))?,
+            seq_tup_read: row
+                .try_get("seq_tup_read")
+                .map_err(|e| format!("Failed to get seq_tup_read: {}", e))?,
+            table_name,
+            column_name,
+            recommendation,
+        });
+    }
+
+    Ok(recommendations)
+}
+
+#[tauri::command]
+pub async fn find_missing_indexes_on_foreign_keys(
+    config: ConnectionConfig,
+    schema: String,
+) -> Result<Vec<MissingFkIndex>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // An index covers the FK when its leading columns are exactly the FK columns
+    let rows = sqlx::query(
+        "SELECT
+            c.relname::text AS table_name,
+            ARRAY(
+                SELECT a.attname::text
+                FROM unnest(con.conkey) WITH ORDINALITY AS k(attnum, ord)
+                JOIN pg_attribute a
+                  ON a.attrelid = con.conrelid
+                 AND a.attnum = k.attnum
+                ORDER BY k.ord
+            ) AS columns,
+            con.confrelid::regclass::text AS references_table
+         FROM pg_constraint con
+         JOIN pg_class c ON c.oid = con.conrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE con.contype = 'f'
+           AND n.nspname = $1
+           AND NOT EXISTS (
+               SELECT 1
+               FROM pg_index i
+               WHERE i.indrelid = con.conrelid
+                 AND (i.indkey::int2[])[0:cardinality(con.conkey) - 1] @> con.conkey
+           )
+         ORDER BY table_name, con.conname",
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch foreign keys: {}", e))?;
+
+    pool.close().await;
+
+    let mut missing = Vec::new();
+    for row in rows {
+        let table_name: String = row
+            .try_get("table_name")
+            .map_err(|e| format!("Failed to get table name: {}", e))?;
+        let columns: Vec<String> = row
+            .try_get("columns")
+            .map_err(|e| format!("Failed to get foreign key columns: {}", e))?;
+
+        let suggested_sql = format!(
+            "CREATE INDEX CONCURRENTLY {} ON {} ({});",
+            quote_identifier(&format!("idx_{}_{}", table_name, columns.join("_"))),
+            qualified_name(&schema, &table_name),
+            columns
+                .iter()
+                .map(|c| quote_identifier(c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        missing.push(MissingFkIndex {
+            references_table: row
+                .try_get("references_table")
+                .map_err(|e| format!("Failed to get referenced table: {}", e))?,
+            column_name: columns.join(", "),
+            table_name,
+            suggested_sql,
+        });
+    }
+
+    Ok(missing)
+}
+
+#[tauri::command]
+pub async fn test_query_performance(
+    config: ConnectionConfig,
+    query: String,
+    iterations: u8,
+) -> Result<PerformanceStats, String> {
+    if iterations == 0 || iterations > MAX_BENCHMARK_ITERATIONS {
+        return Err(format!(
+            "Iterations must be between 1 and {}",
+            MAX_BENCHMARK_ITERATIONS
+        ));
+    }
+
+    // EXPLAIN ANALYZE really executes the query
+    check_read_only_query(&config, &query)?;
+
+    let pool = connect_postgres(&config).await?;
+    let explain = format!(
+        "EXPLAIN (ANALYZE, FORMAT JSON) {}",
+        query.trim().trim_end_matches(';')
+    );
+
+    let mut samples = Vec::new();
+    for _ in 0..iterations {
+        // Roll back every run so writes neither persist nor skew later iterations
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        let plan: serde_json::Value = sqlx::query_scalar(&explain)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| format!("Error executing query: {}", e))?;
+
+        tx.rollback()
+            .await
+            .map_err(|e| format!("Failed to roll back transaction: {}", e))?;
+
+        let total_time = plan[0]["Plan"]["Actual Total Time"]
+            .as_f64()
+            .ok_or("EXPLAIN output did not include an actual total time")?;
+        samples.push(total_time);
+    }
+
+    pool.close().await;
+
+    Ok(performance_stats(samples))
+}
+
+// Helper function to summarize timings; percentiles use the nearest-rank method
+fn performance_stats(samples: Vec<f64>) -> PerformanceStats {
+    let mut sorted = samples.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let percentile = |p: f64| {
+        let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    };
+
+    PerformanceStats {
+        min_ms: sorted[0],
+        max_ms: sorted[sorted.len() - 1],
+        avg_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        p50_ms: percentile(50.0),
+        p95_ms: percentile(95.0),
+        samples,
+    }
+}
+
+#[tauri::command]
+pub async fn get_query_cost_estimate(
+    config: ConnectionConfig,
+    query: String,
+) -> Result<QueryCostEstimate, String> {
+    // Plain EXPLAIN only plans the query, so it is safe in read-only mode
+    let pool = connect_postgres(&config).await?;
+    let explain = format!(
+        "EXPLAIN (FORMAT JSON) {}",
+        query.trim().trim_end_matches(';')
+    );
+
+    let plan: serde_json::Value = sqlx::query_scalar(&explain)
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| format!("Error explaining query: {}", e))?;
+
+    pool.close().await;
+
+    let root = &plan[0]["Plan"];
+    let field = |name: &str| {
+        root[name]
+            .as_f64()
+            .ok_or_else(|| format!("EXPLAIN output did not include '{}'", name))
+    };
+
+    Ok(QueryCostEstimate {
+        startup_cost: field("Startup Cost")?,
+        total_cost: field("Total Cost")?,
+        plan_rows: field("Plan Rows")?,
+        plan_width: field("Plan Width")? as i32,
+    })
+}
+
+#[tauri::command]
+pub async fn explain_analyze_structured(
+    config: ConnectionConfig,
+    query: String,
+    buffers: bool,
+) -> Result<PlanNode, String> {
+    // EXPLAIN ANALYZE really executes the query
+    check_read_only_query(&config, &query)?;
+
+    let pool = connect_postgres(&config).await?;
+    let options = if buffers {
+        "ANALYZE, BUFFERS, FORMAT JSON"
+    } else {
+        "ANALYZE, FORMAT JSON"
+    };
+    let explain = format!(
+        "EXPLAIN ({}) {}",
+        options,
+        query.trim().trim_end_matches(';')
+    );
+
+    // Roll back so analyzing a write does not persist it
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    let plan: serde_json::Value = sqlx::query_scalar(&explain)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| format!("Error executing query: {}", e))?;
+
+    tx.rollback()
+        .await
+        .map_err(|e| format!("Failed to roll back transaction: {}", e))?;
+
+    pool.close().await;
+
+    parse_explain_json(plan)
+}
+
+// Helper function to convert EXPLAIN (FORMAT JSON) output, or a single plan node, into a tree
+fn parse_explain_json(value: serde_json::Value) -> Result<PlanNode, String> {
+    let node = match &value {
+        serde_json::Value::Array(_) => &value[0]["Plan"],
+        _ if value.get("Plan").is_some() => &value["Plan"],
+        _ => &value,
+    };
+
+    let node_type = node["Node Type"]
+        .as_str()
+        .ok_or("EXPLAIN output did not include a node type")?
+        .to_string();
+    let field = |name: &str| {
+        node[name]
+            .as_f64()
+            .ok_or_else(|| format!("EXPLAIN output did not include '{}'", name))
+    };
+
+    let children = match node["Plans"].as_array() {
+        Some(plans) => plans
+            .iter()
+            .map(|child| parse_explain_json(child.clone()))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Vec::new(),
+    };
+
+    Ok(PlanNode {
+        node_type,
+        startup_cost: field("Startup Cost")?,
+        total_cost: field("Total Cost")?,
+        plan_rows: field("Plan Rows")?,
+        actual_rows: node["Actual Rows"].as_f64(),
+        actual_time_ms: node["Actual Total Time"].as_f64(),
+        actual_loops: node["Actual Loops"].as_u64().map(|loops| loops as u32),
+        relation_name: node["Relation Name"].as_str().map(String::from),
+        children,
+    })
+}
+
+#[tauri::command]
+pub async fn get_column_statistics_histogram(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: String,
+    column_name: String,
+) -> Result<ColumnHistogram, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // anyarray columns can only be cast through text; correlation is NULL for unsortable types
+    let row = sqlx::query(
+        "SELECT
+            COALESCE(to_jsonb(histogram_bounds::text::text[]), '[]'::jsonb) AS bounds,
+            null_frac::float8 AS null_fraction,
+            avg_width,
+            COALESCE(to_jsonb(most_common_vals::text::text[]), '[]'::jsonb) AS most_common_values,
+            COALESCE(most_common_freqs::float8[], ARRAY[]::float8[]) AS most_common_freqs,
+            COALESCE(correlation, 0)::float8 AS correlation
+         FROM pg_stats
+         WHERE attname = $1 AND tablename = $2 AND schemaname = $3",
+    )
+    .bind(&column_name)
+    .bind(&table_name)
+    .bind(&schema)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch column statistics: {}", e))?;
+
+    pool.close().await;
+
+    let row = row.ok_or_else(|| {
+        format!(
+            "No statistics for {}.{}; run ANALYZE on the table first",
+            qualified_name(&schema, &table_name),
+            column_name
+        )
+    })?;
+
+    let json_array = |column: &str| -> Result<Vec<serde_json::Value>, String> {
+        row.try_get::<serde_json::Value, _>(column)
+            .map(|value| value.as_array().cloned().unwrap_or_default())
+            .map_err(|e| format!("Failed to get {}: {}", column, e))
+    };
+
+    Ok(ColumnHistogram {
+        bounds: json_array("bounds")?,
+        null_fraction: row
+            .try_get("null_fraction")
+            .map_err(|e| format!("Failed to get null_fraction: {}", e))?,
+        avg_width: row
+            .try_get("avg_width")
+            .map_err(|e| format!("Failed to get avg_width: {}", e))?,
+        most_common_values: json_array("most_common_values")?,
+        most_common_freqs: row
+            .try_get("most_common_freqs")
+            .map_err(|e| format!("Failed to get most_common_freqs: {}", e))?,
+        correlation: row
+            .try_get("correlation")
+            .map_err(|e| format!("Failed to get correlation: {}", e))?,
+    })
+}
+
+#[tauri::command]
+pub fn suggest_query_optimizations(query: String) -> Result<Vec<QuerySuggestion>, String> {
+    if query.trim().is_empty() {
+        return Err("Query is empty".to_string());
+    }
+
+    Ok(suggest_optimizations(&query))
+}
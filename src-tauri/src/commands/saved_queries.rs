@@ -1,7 +1,10 @@
 use crate::models::SavedQuery;
-use crate::storage::get_saved_queries_db;
+use crate::storage::{get_history_db, get_saved_queries_db};
 use crate::utils::get_app_dir;
 
+// (id, name, query, description, is_pinned, sort_order, created_at, updated_at)
+type SavedQueryRow = (i64, String, String, Option<String>, bool, i32, String, String);
+
 #[tauri::command]
 pub async fn save_query(
     name: String,
@@ -35,6 +38,7 @@ pub async fn save_query(
         query,
         description,
         is_pinned: false,
+        sort_order: 0,
         created_at: now.clone(),
         updated_at: now,
     })
@@ -45,10 +49,10 @@ pub async fn get_saved_queries() -> Result<Vec<SavedQuery>, String> {
     let app_dir = get_app_dir()?;
     let pool = get_saved_queries_db(app_dir).await?;
 
-    let rows = sqlx::query_as::<_, (i64, String, String, Option<String>, bool, String, String)>(
-        "SELECT id, name, query, description, is_pinned, created_at, updated_at
+    let rows = sqlx::query_as::<_, SavedQueryRow>(
+        "SELECT id, name, query, description, is_pinned, sort_order, created_at, updated_at
          FROM saved_queries
-         ORDER BY is_pinned DESC, name ASC",
+         ORDER BY is_pinned DESC, sort_order ASC, name ASC",
     )
     .fetch_all(&pool)
     .await
@@ -59,14 +63,17 @@ pub async fn get_saved_queries() -> Result<Vec<SavedQuery>, String> {
     let queries = rows
         .into_iter()
         .map(
-            |(id, name, query, description, is_pinned, created_at, updated_at)| SavedQuery {
-                id,
-                name,
-                query,
-                description,
-                is_pinned,
-                created_at,
-                updated_at,
+            |(id, name, query, description, is_pinned, sort_order, created_at, updated_at)| {
+                SavedQuery {
+                    id,
+                    name,
+                    query,
+                    description,
+                    is_pinned,
+                    sort_order,
+                    created_at,
+                    updated_at,
+                }
             },
         )
         .collect();
@@ -114,3 +121,123 @@ pub async fn toggle_pin_query(id: i64) -> Result<bool, String> {
 
     Ok(new_pin_status)
 }
+
+#[tauri::command]
+pub async fn rename_saved_query(id: i64, new_name: String) -> Result<(), String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_saved_queries_db(app_dir).await?;
+
+    let conflicts = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM saved_queries WHERE name = ? AND id != ?",
+    )
+    .bind(&new_name)
+    .bind(id)
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("Failed to check query name: {}", e))?;
+
+    if conflicts > 0 {
+        pool.close().await;
+        return Err("Name already exists".to_string());
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let result = sqlx::query("UPDATE saved_queries SET name = ?, updated_at = ? WHERE id = ?")
+        .bind(&new_name)
+        .bind(&now)
+        .bind(id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to rename query: {}", e))?;
+
+    pool.close().await;
+
+    if result.rows_affected() == 0 {
+        return Err(format!("Saved query {} not found", id));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_saved_query_by_id(id: i64) -> Result<SavedQuery, String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_saved_queries_db(app_dir).await?;
+
+    let row = sqlx::query_as::<_, SavedQueryRow>(
+        "SELECT id, name, query, description, is_pinned, sort_order, created_at, updated_at
+         FROM saved_queries
+         WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch saved query: {}", e))?;
+
+    pool.close().await;
+
+    let (id, name, query, description, is_pinned, sort_order, created_at, updated_at) =
+        row.ok_or_else(|| format!("Saved query {} not found", id))?;
+
+    Ok(SavedQuery {
+        id,
+        name,
+        query,
+        description,
+        is_pinned,
+        sort_order,
+        created_at,
+        updated_at,
+    })
+}
+
+#[tauri::command]
+pub async fn reorder_saved_queries(ordered_ids: Vec<i64>) -> Result<(), String> {
+    let app_dir = get_app_dir()?;
+    let pool = get_saved_queries_db(app_dir).await?;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    for (position, id) in ordered_ids.iter().enumerate() {
+        sqlx::query("UPDATE saved_queries SET sort_order = ? WHERE id = ?")
+            .bind(position as i32)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to update sort order: {}", e))?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit sort order: {}", e))?;
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn save_query_from_history(
+    history_id: i64,
+    name: String,
+    description: Option<String>,
+) -> Result<SavedQuery, String> {
+    let app_dir = get_app_dir()?;
+    let history_pool = get_history_db(app_dir).await?;
+
+    let query = sqlx::query_scalar::<_, String>("SELECT query FROM query_history WHERE id = ?")
+        .bind(history_id)
+        .fetch_optional(&history_pool)
+        .await
+        .map_err(|e| format!("Failed to fetch history entry: {}", e))?;
+
+    history_pool.close().await;
+
+    let query = query.ok_or_else(|| format!("History entry {} not found", history_id))?;
+
+    save_query(name, query, description).await
+}
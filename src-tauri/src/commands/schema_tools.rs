@@ -0,0 +1,197 @@
+use crate::commands::get_enhanced_database_schema;
+use crate::models::{CloneResult, ConnectionConfig};
+use crate::state::AuditLogger;
+use crate::utils::{
+    connect_postgres, execute_batch, qualified_name, quote_identifier, quote_literal,
+};
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use std::collections::HashMap;
+
+#[tauri::command]
+pub async fn clone_schema(
+    config: ConnectionConfig,
+    source_schema: String,
+    target_schema: String,
+    include_data: bool,
+    audit: tauri::State<'_, AuditLogger>,
+) -> Result<CloneResult, String> {
+    if config.read_only {
+        return Err("Read-only mode: cloning a schema is not allowed".to_string());
+    }
+
+    let source =
+        get_enhanced_database_schema(config.clone(), Some(source_schema.clone())).await?;
+
+    let pool = connect_postgres(&config).await?;
+    let catalog = fetch_clone_catalog(&pool, &source_schema).await;
+    let mut catalog = match catalog {
+        Ok(catalog) => catalog,
+        Err(e) => {
+            pool.close().await;
+            return Err(e);
+        }
+    };
+
+    let mut statements = vec![format!("CREATE SCHEMA {}", quote_identifier(&target_schema))];
+    let mut tables_created = 0;
+    let mut indexes_created = 0;
+
+    for table in &source.tables {
+        let source_table = qualified_name(&source_schema, &table.table_name);
+        let target_table = qualified_name(&target_schema, &table.table_name);
+
+        // Brings defaults, NOT NULL, CHECK, identity, and every index including the ones
+        // behind primary key and unique constraints. Serial defaults keep using the
+        // source schema's sequences.
+        statements.push(format!(
+            "CREATE TABLE {} (LIKE {} INCLUDING ALL)",
+            target_table, source_table
+        ));
+        tables_created += 1;
+        indexes_created += table.indexes.len() as u32;
+
+        let details = catalog.tables.remove(&table.table_name).unwrap_or_default();
+        if include_data && !details.insert_columns.is_empty() {
+            // Generated columns are computed again; identity values are copied as they are
+            statements.push(format!(
+                "INSERT INTO {} ({}) OVERRIDING SYSTEM VALUE SELECT {} FROM {}",
+                target_table, details.insert_columns, details.insert_columns, source_table
+            ));
+            for column in &details.identity_columns {
+                statements.push(format!(
+                    "DO $clone$ BEGIN PERFORM setval(pg_get_serial_sequence({}, {}), max({})) \
+                     FROM {} HAVING max({}) IS NOT NULL; END $clone$",
+                    quote_literal(&target_table),
+                    quote_literal(column),
+                    quote_identifier(column),
+                    target_table,
+                    quote_identifier(column)
+                ));
+            }
+        }
+    }
+
+    // Added last so every referenced table exists and holds its rows
+    let mut foreign_keys_created = 0;
+    for (table_name, constraint_name, definition) in catalog.foreign_keys {
+        statements.push(format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} {}",
+            qualified_name(&target_schema, &table_name),
+            quote_identifier(&constraint_name),
+            retarget_references(&definition, &source_schema, &target_schema)
+        ));
+        foreign_keys_created += 1;
+    }
+
+    let result = execute_batch(&pool, &statements).await;
+    pool.close().await;
+    let row_count = result?;
+
+    let _ = audit.record(&config, &statements.join(";\n"), row_count);
+
+    Ok(CloneResult {
+        tables_created,
+        indexes_created,
+        foreign_keys_created,
+    })
+}
+
+struct CloneCatalog {
+    tables: HashMap<String, CloneTableDetails>,
+    foreign_keys: Vec<(String, String, String)>, // (table, constraint, pg_get_constraintdef())
+}
+
+#[derive(Default)]
+struct CloneTableDetails {
+    insert_columns: String, // quoted, comma-separated columns that accept values
+    identity_columns: Vec<String>,
+}
+
+// Catalog details CREATE TABLE ... LIKE does not carry over. Run with only pg_catalog on
+// the search_path so pg_get_constraintdef() schema-qualifies every referenced table.
+async fn fetch_clone_catalog(pool: &PgPool, schema: &str) -> Result<CloneCatalog, String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+    sqlx::query("SET LOCAL search_path TO pg_catalog")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to set search_path: {}", e))?;
+
+    let table_rows = sqlx::query(
+        "SELECT c.relname::text AS table_name,
+                COALESCE(string_agg(quote_ident(a.attname), ', ' ORDER BY a.attnum)
+                         FILTER (WHERE a.attgenerated = ''), '') AS insert_columns,
+                COALESCE(array_agg(a.attname::text ORDER BY a.attnum)
+                         FILTER (WHERE a.attidentity <> ''), '{}') AS identity_columns
+         FROM pg_class c
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         JOIN pg_attribute a ON a.attrelid = c.oid AND a.attnum > 0 AND NOT a.attisdropped
+         WHERE n.nspname = $1 AND c.relkind IN ('r', 'p')
+         GROUP BY c.relname",
+    )
+    .bind(schema)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch columns: {}", e))?;
+
+    let mut tables = HashMap::new();
+    for row in table_rows {
+        tables.insert(
+            row.try_get("table_name").unwrap_or_default(),
+            CloneTableDetails {
+                insert_columns: row.try_get("insert_columns").unwrap_or_default(),
+                identity_columns: row.try_get("identity_columns").unwrap_or_default(),
+            },
+        );
+    }
+
+    let fk_rows = sqlx::query(
+        "SELECT c.relname::text AS table_name,
+                con.conname::text AS constraint_name,
+                pg_get_constraintdef(con.oid) AS definition
+         FROM pg_constraint con
+         JOIN pg_class c ON c.oid = con.conrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1 AND con.contype = 'f'
+         ORDER BY c.relname, con.conname",
+    )
+    .bind(schema)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch foreign keys: {}", e))?;
+
+    let _ = tx.rollback().await;
+
+    let foreign_keys = fk_rows
+        .iter()
+        .map(|row| {
+            (
+                row.try_get("table_name").unwrap_or_default(),
+                row.try_get("constraint_name").unwrap_or_default(),
+                row.try_get("definition").unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    Ok(CloneCatalog {
+        tables,
+        foreign_keys,
+    })
+}
+
+// Helper function to point "REFERENCES source.table" in a foreign key at the target schema.
+// Keys to tables in other schemas keep referencing the originals.
+fn retarget_references(definition: &str, source_schema: &str, target_schema: &str) -> String {
+    let target = format!("REFERENCES {}.", quote_identifier(target_schema));
+    let quoted_source = format!("REFERENCES {}.", quote_identifier(source_schema));
+    let bare_source = format!("REFERENCES {}.", source_schema);
+
+    if definition.contains(&quoted_source) {
+        definition.replacen(&quoted_source, &target, 1)
+    } else {
+        definition.replacen(&bare_source, &target, 1)
+    }
+}
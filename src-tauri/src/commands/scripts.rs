@@ -0,0 +1,219 @@
+use crate::constants::MAX_SQL_FILE_SIZE_BYTES;
+use crate::models::{
+    ConnectionConfig, ExecutionSummary, MigrationApplicationResult, MigrationError,
+    MigrationProgressPayload,
+};
+use crate::state::AuditLogger;
+use crate::utils::{connect_postgres, execute_batch, split_sql_script};
+use std::fs;
+use tauri::{AppHandle, Emitter};
+
+#[tauri::command]
+pub async fn import_sql_file_and_execute(
+    config: ConnectionConfig,
+    file_path: String,
+    transactional: bool,
+    audit: tauri::State<'_, AuditLogger>,
+) -> Result<ExecutionSummary, String> {
+    if config.read_only {
+        return Err("Read-only mode: executing SQL files is not allowed".to_string());
+    }
+
+    let metadata =
+        fs::metadata(&file_path).map_err(|e| format!("Failed to read {}: {}", file_path, e))?;
+    if metadata.len() > MAX_SQL_FILE_SIZE_BYTES {
+        return Err(format!(
+            "File is too large ({} bytes); the limit is {} bytes",
+            metadata.len(),
+            MAX_SQL_FILE_SIZE_BYTES
+        ));
+    }
+
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read {}: {}", file_path, e))?;
+    let statements = split_sql_script(&contents);
+
+    let start = std::time::Instant::now();
+    let pool = connect_postgres(&config).await?;
+
+    let result = if transactional {
+        execute_batch(&pool, &statements).await
+    } else {
+        execute_each(&pool, &statements).await
+    };
+    pool.close().await;
+    let rows_affected = result?;
+
+    let _ = audit.record(&config, &statements.join(";\n"), rows_affected);
+
+    Ok(ExecutionSummary {
+        statements_executed: statements.len() as u32,
+        rows_affected,
+        duration_ms: start.elapsed().as_millis(),
+    })
+}
+
+#[tauri::command]
+pub async fn apply_migration_script(
+    app: AppHandle,
+    config: ConnectionConfig,
+    script: String,
+    dry_run: bool,
+    audit: tauri::State<'_, AuditLogger>,
+) -> Result<MigrationApplicationResult, String> {
+    if config.read_only {
+        return Err("Read-only mode: applying migrations is not allowed".to_string());
+    }
+
+    let statements = split_sql_script(&script);
+    let total_statements = statements.len() as u32;
+
+    let start = std::time::Instant::now();
+    let pool = connect_postgres(&config).await?;
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    // ALTER TYPE ... ADD VALUE cannot run in a transaction before PostgreSQL 12, and
+    // from 12 on the new value cannot be used until it commits. A real run therefore
+    // commits those statements one by one on another connection first.
+    let (early, transactional): (Vec<usize>, Vec<usize>) =
+        (0..statements.len()).partition(|&i| !dry_run && is_enum_add_value(&statements[i]));
+
+    // A real run stops at the first error and rolls everything back. A dry run
+    // isolates each statement in a savepoint so every failure gets reported.
+    let mut statements_executed = 0;
+    let mut rows_affected = 0;
+    let mut errors = Vec::new();
+    let mut committed_early = Vec::new();
+    for &i in early.iter().chain(&transactional) {
+        let statement = &statements[i];
+        if early.contains(&i) {
+            let result = sqlx::raw_sql(statement).execute(&pool).await;
+            let success = result.is_ok();
+            match result {
+                Ok(_) => {
+                    statements_executed += 1;
+                    committed_early.push(statement.as_str());
+                }
+                Err(e) => errors.push(MigrationError {
+                    statement_index: i as u32,
+                    statement: statement.clone(),
+                    message: e.to_string(),
+                }),
+            }
+            let _ = app.emit(
+                "migration-progress",
+                MigrationProgressPayload {
+                    statement_index: i as u32,
+                    total_statements,
+                    success,
+                },
+            );
+            if !success {
+                break;
+            }
+            continue;
+        }
+
+        if dry_run {
+            sqlx::raw_sql("SAVEPOINT migration_statement")
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to create savepoint: {}", e))?;
+        }
+
+        let result = sqlx::raw_sql(statement).execute(&mut *tx).await;
+        let success = result.is_ok();
+        match result {
+            Ok(done) => {
+                statements_executed += 1;
+                rows_affected += done.rows_affected();
+            }
+            Err(e) => errors.push(MigrationError {
+                statement_index: i as u32,
+                statement: statement.clone(),
+                message: e.to_string(),
+            }),
+        }
+
+        let _ = app.emit(
+            "migration-progress",
+            MigrationProgressPayload {
+                statement_index: i as u32,
+                total_statements,
+                success,
+            },
+        );
+
+        if dry_run {
+            let restore = if success {
+                "RELEASE SAVEPOINT migration_statement"
+            } else {
+                "ROLLBACK TO SAVEPOINT migration_statement"
+            };
+            sqlx::raw_sql(restore)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to restore savepoint: {}", e))?;
+        } else if !success {
+            break;
+        }
+    }
+
+    let applied = !dry_run && errors.is_empty();
+    if applied {
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+    } else {
+        tx.rollback()
+            .await
+            .map_err(|e| format!("Failed to roll back transaction: {}", e))?;
+    }
+    pool.close().await;
+
+    if applied {
+        let _ = audit.record(&config, &statements.join(";\n"), rows_affected);
+    } else if !committed_early.is_empty() {
+        // Added enum values stay committed even though the rest rolled back
+        let _ = audit.record(&config, &committed_early.join(";\n"), 0);
+    }
+
+    Ok(MigrationApplicationResult {
+        statements_executed,
+        statements_skipped: total_statements - statements_executed - errors.len() as u32,
+        errors,
+        duration_ms: start.elapsed().as_millis(),
+    })
+}
+
+// Whether a statement is ALTER TYPE ... ADD VALUE, ignoring case and spacing
+fn is_enum_add_value(statement: &str) -> bool {
+    let normalized = statement
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase();
+    normalized.starts_with("ALTER TYPE ") && normalized.contains(" ADD VALUE ")
+}
+
+// Helper function to run statements one at a time, keeping whatever succeeded
+// before a failure (for scripts with e.g. CREATE INDEX CONCURRENTLY)
+async fn execute_each(pool: &sqlx::PgPool, statements: &[String]) -> Result<u64, String> {
+    let mut rows_affected = 0;
+    for (i, statement) in statements.iter().enumerate() {
+        let result = sqlx::raw_sql(statement).execute(pool).await.map_err(|e| {
+            format!(
+                "Error executing statement {} of {} (earlier statements were applied): {}",
+                i + 1,
+                statements.len(),
+                e
+            )
+        })?;
+        rows_affected += result.rows_affected();
+    }
+
+    Ok(rows_affected)
+}
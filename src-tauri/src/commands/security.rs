@@ -0,0 +1,262 @@
+use crate::models::{ConnectionConfig, PermissionsReport, RlsPolicy, RolePermissions, TableGrant};
+use crate::utils::connect_postgres;
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+
+#[tauri::command]
+pub async fn get_role_permissions(
+    config: ConnectionConfig,
+    role_name: String,
+) -> Result<RolePermissions, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let role_row = sqlx::query(
+        "SELECT rolsuper, rolcanlogin, rolconnlimit
+         FROM pg_roles
+         WHERE rolname = $1",
+    )
+    .bind(&role_name)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch role: {}", e))?;
+
+    let Some(role_row) = role_row else {
+        pool.close().await;
+        return Err(format!("Role '{}' does not exist", role_name));
+    };
+
+    let is_superuser: bool = role_row
+        .try_get("rolsuper")
+        .map_err(|e| format!("Failed to get rolsuper: {}", e))?;
+    let can_login: bool = role_row
+        .try_get("rolcanlogin")
+        .map_err(|e| format!("Failed to get rolcanlogin: {}", e))?;
+    let connection_limit: i32 = role_row
+        .try_get("rolconnlimit")
+        .map_err(|e| format!("Failed to get rolconnlimit: {}", e))?;
+
+    let grant_rows = sqlx::query(
+        "SELECT
+            table_schema::text AS table_schema,
+            table_name::text AS table_name,
+            privilege_type::text AS privilege_type,
+            is_grantable::text AS is_grantable
+         FROM information_schema.role_table_grants
+         WHERE grantee = $1
+         ORDER BY table_schema, table_name, privilege_type",
+    )
+    .bind(&role_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch table grants: {}", e))?;
+
+    let mut table_grants = Vec::new();
+    for row in grant_rows {
+        let is_grantable: String = row
+            .try_get("is_grantable")
+            .map_err(|e| format!("Failed to get is_grantable: {}", e))?;
+
+        table_grants.push(TableGrant {
+            table_schema: row
+                .try_get("table_schema")
+                .map_err(|e| format!("Failed to get table schema: {}", e))?,
+            table_name: row
+                .try_get("table_name")
+                .map_err(|e| format!("Failed to get table name: {}", e))?,
+            privilege_type: row
+                .try_get("privilege_type")
+                .map_err(|e| format!("Failed to get privilege type: {}", e))?,
+            is_grantable: is_grantable == "YES",
+        });
+    }
+
+    let schema_rows = sqlx::query(
+        "SELECT
+            n.nspname::text AS schema_name,
+            has_schema_privilege($1, n.oid, 'USAGE') AS has_usage,
+            has_schema_privilege($1, n.oid, 'CREATE') AS has_create
+         FROM pg_namespace n
+         WHERE n.nspname NOT IN ('information_schema', 'pg_catalog', 'pg_toast')
+           AND n.nspname NOT LIKE 'pg_temp_%'
+           AND n.nspname NOT LIKE 'pg_toast_temp_%'
+         ORDER BY n.nspname",
+    )
+    .bind(&role_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch schema privileges: {}", e))?;
+
+    pool.close().await;
+
+    let mut schema_grants = Vec::new();
+    for row in schema_rows {
+        let schema_name: String = row
+            .try_get("schema_name")
+            .map_err(|e| format!("Failed to get schema name: {}", e))?;
+        let has_usage: bool = row
+            .try_get("has_usage")
+            .map_err(|e| format!("Failed to get usage privilege: {}", e))?;
+        let has_create: bool = row
+            .try_get("has_create")
+            .map_err(|e| format!("Failed to get create privilege: {}", e))?;
+
+        if has_usage {
+            schema_grants.push(format!("{}: USAGE", schema_name));
+        }
+        if has_create {
+            schema_grants.push(format!("{}: CREATE", schema_name));
+        }
+    }
+
+    Ok(RolePermissions {
+        table_grants,
+        schema_grants,
+        is_superuser,
+        can_login,
+        // -1 means no limit
+        connection_limit: (connection_limit >= 0).then_some(connection_limit),
+    })
+}
+
+#[tauri::command]
+pub async fn get_row_level_security_policies(
+    config: ConnectionConfig,
+    schema: String,
+    table_name: Option<String>,
+) -> Result<Vec<RlsPolicy>, String> {
+    let pool = connect_postgres(&config).await?;
+    let policies = fetch_rls_policies(&pool, &schema, table_name.as_deref()).await;
+    pool.close().await;
+    policies
+}
+
+#[tauri::command]
+pub async fn check_connection_permissions(
+    config: ConnectionConfig,
+) -> Result<PermissionsReport, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // A privilege counts if the connected user holds it on at least one user table
+    let row = sqlx::query(
+        "WITH user_tables AS (
+            SELECT c.oid
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relkind IN ('r', 'p')
+              AND n.nspname NOT IN ('information_schema', 'pg_catalog', 'pg_toast')
+              AND n.nspname NOT LIKE 'pg_temp_%'
+         ),
+         user_schemas AS (
+            SELECT nspname
+            FROM pg_namespace
+            WHERE nspname NOT IN ('information_schema', 'pg_catalog', 'pg_toast')
+              AND nspname NOT LIKE 'pg_temp_%'
+              AND nspname NOT LIKE 'pg_toast_temp_%'
+         )
+         SELECT
+            COALESCE((SELECT bool_or(has_table_privilege(oid, 'SELECT')) FROM user_tables), false) AS can_select,
+            COALESCE((SELECT bool_or(has_table_privilege(oid, 'INSERT')) FROM user_tables), false) AS can_insert,
+            COALESCE((SELECT bool_or(has_table_privilege(oid, 'UPDATE')) FROM user_tables), false) AS can_update,
+            COALESCE((SELECT bool_or(has_table_privilege(oid, 'DELETE')) FROM user_tables), false) AS can_delete,
+            COALESCE((SELECT bool_or(has_schema_privilege(nspname, 'CREATE')) FROM user_schemas), false) AS can_create_table,
+            (SELECT rolsuper FROM pg_roles WHERE rolname = current_user) AS is_superuser,
+            ARRAY(
+                SELECT nspname::text
+                FROM user_schemas
+                WHERE has_schema_privilege(nspname, 'USAGE')
+                ORDER BY nspname
+            ) AS schemas_accessible",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("Failed to check permissions: {}", e))?;
+
+    pool.close().await;
+
+    Ok(PermissionsReport {
+        can_select: row
+            .try_get("can_select")
+            .map_err(|e| format!("Failed to get can_select: {}", e))?,
+        can_insert: row
+            .try_get("can_insert")
+            .map_err(|e| format!("Failed to get can_insert: {}", e))?,
+        can_update: row
+            .try_get("can_update")
+            .map_err(|e| format!("Failed to get can_update: {}", e))?,
+        can_delete: row
+            .try_get("can_delete")
+            .map_err(|e| format!("Failed to get can_delete: {}", e))?,
+        can_create_table: row
+            .try_get("can_create_table")
+            .map_err(|e| format!("Failed to get can_create_table: {}", e))?,
+        is_superuser: row
+            .try_get("is_superuser")
+            .map_err(|e| format!("Failed to get is_superuser: {}", e))?,
+        schemas_accessible: row
+            .try_get("schemas_accessible")
+            .map_err(|e| format!("Failed to get accessible schemas: {}", e))?,
+    })
+}
+
+// Helper function to fetch RLS policies for a schema, optionally limited to one table
+pub(crate) async fn fetch_rls_policies(
+    pool: &PgPool,
+    schema: &str,
+    table_name: Option<&str>,
+) -> Result<Vec<RlsPolicy>, String> {
+    let policy_rows = sqlx::query(
+        "SELECT
+            pol.polname::text AS policy_name,
+            c.relname::text AS table_name,
+            CASE pol.polcmd
+                WHEN 'r' THEN 'SELECT'
+                WHEN 'a' THEN 'INSERT'
+                WHEN 'w' THEN 'UPDATE'
+                WHEN 'd' THEN 'DELETE'
+                ELSE 'ALL'
+            END AS command,
+            ARRAY(
+                SELECT CASE WHEN r = 0 THEN 'public' ELSE pg_get_userbyid(r)::text END
+                FROM unnest(pol.polroles) AS r
+            ) AS roles,
+            pg_get_expr(pol.polqual, pol.polrelid) AS using_expr,
+            pg_get_expr(pol.polwithcheck, pol.polrelid) AS check_expr,
+            NOT pol.polpermissive AS restrictive
+         FROM pg_policy pol
+         JOIN pg_class c ON c.oid = pol.polrelid
+         JOIN pg_namespace n ON n.oid = c.relnamespace
+         WHERE n.nspname = $1
+           AND ($2::text IS NULL OR c.relname = $2)
+         ORDER BY c.relname, pol.polname",
+    )
+    .bind(schema)
+    .bind(table_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch RLS policies: {}", e))?;
+
+    let mut policies = Vec::new();
+    for row in policy_rows {
+        policies.push(RlsPolicy {
+            policy_name: row
+                .try_get("policy_name")
+                .map_err(|e| format!("Failed to get policy name: {}", e))?,
+            table_name: row
+                .try_get("table_name")
+                .map_err(|e| format!("Failed to get table name: {}", e))?,
+            command: row
+                .try_get("command")
+                .map_err(|e| format!("Failed to get policy command: {}", e))?,
+            roles: row
+                .try_get("roles")
+                .map_err(|e| format!("Failed to get policy roles: {}", e))?,
+            using_expr: row.try_get("using_expr").ok().flatten(),
+            check_expr: row.try_get("check_expr").ok().flatten(),
+            restrictive: row
+                .try_get("restrictive")
+                .map_err(|e| format!("Failed to get policy kind: {}", e))?,
+        });
+    }
+
+    Ok(policies)
+}
@@ -0,0 +1,281 @@
+use crate::constants::SSD_RANDOM_PAGE_COST;
+use crate::models::{
+    ConnectionConfig, HbaRule, PgSetting, PlannerSettings, SubscriptionStatus, WaitEvent,
+};
+use crate::utils::connect_postgres;
+use sqlx::Row;
+
+#[tauri::command]
+pub async fn get_pg_settings(
+    config: ConnectionConfig,
+    filter: Option<String>,
+) -> Result<Vec<PgSetting>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let rows = sqlx::query(
+        "SELECT name, setting, unit, category, short_desc, source, min_val, max_val
+         FROM pg_settings
+         WHERE $1::text IS NULL OR name LIKE $1",
+    )
+    .bind(&filter)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch settings: {}", e))?;
+
+    pool.close().await;
+
+    let mut settings = Vec::new();
+    for row in rows {
+        settings.push(PgSetting {
+            name: row
+                .try_get("name")
+                .map_err(|e| format!("Failed to get setting name: {}", e))?,
+            setting: row
+                .try_get("setting")
+                .map_err(|e| format!("Failed to get setting value: {}", e))?,
+            unit: row.try_get("unit").ok().flatten(),
+            category: row
+                .try_get("category")
+                .map_err(|e| format!("Failed to get category: {}", e))?,
+            short_desc: row
+                .try_get("short_desc")
+                .map_err(|e| format!("Failed to get description: {}", e))?,
+            source: row
+                .try_get("source")
+                .map_err(|e| format!("Failed to get source: {}", e))?,
+            min_val: row.try_get("min_val").ok().flatten(),
+            max_val: row.try_get("max_val").ok().flatten(),
+        });
+    }
+
+    // Group by category, then alphabetical within each category
+    settings.sort_by(|a, b| a.category.cmp(&b.category).then_with(|| a.name.cmp(&b.name)));
+
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn get_planner_settings(config: ConnectionConfig) -> Result<PlannerSettings, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // current_setting keeps the unit on effective_cache_size, e.g. "4GB"
+    let row = sqlx::query(
+        "SELECT
+            current_setting('seq_page_cost')::float8 AS seq_page_cost,
+            current_setting('random_page_cost')::float8 AS random_page_cost,
+            current_setting('cpu_tuple_cost')::float8 AS cpu_tuple_cost,
+            current_setting('cpu_index_tuple_cost')::float8 AS cpu_index_tuple_cost,
+            current_setting('effective_cache_size') AS effective_cache_size,
+            current_setting('parallel_setup_cost')::float8 AS parallel_setup_cost",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch planner settings: {}", e))?;
+
+    pool.close().await;
+
+    let random_page_cost: f64 = row
+        .try_get("random_page_cost")
+        .map_err(|e| format!("Failed to get random_page_cost: {}", e))?;
+
+    // The default of 4.0 assumes spinning disks
+    let recommendation = (random_page_cost > SSD_RANDOM_PAGE_COST).then(|| {
+        format!(
+            "random_page_cost is {}; {} is recommended for SSD storage",
+            random_page_cost, SSD_RANDOM_PAGE_COST
+        )
+    });
+
+    Ok(PlannerSettings {
+        seq_page_cost: row
+            .try_get("seq_page_cost")
+            .map_err(|e| format!("Failed to get seq_page_cost: {}", e))?,
+        random_page_cost,
+        cpu_tuple_cost: row
+            .try_get("cpu_tuple_cost")
+            .map_err(|e| format!("Failed to get cpu_tuple_cost: {}", e))?,
+        cpu_index_tuple_cost: row
+            .try_get("cpu_index_tuple_cost")
+            .map_err(|e| format!("Failed to get cpu_index_tuple_cost: {}", e))?,
+        effective_cache_size: row
+            .try_get("effective_cache_size")
+            .map_err(|e| format!("Failed to get effective_cache_size: {}", e))?,
+        parallel_setup_cost: row
+            .try_get("parallel_setup_cost")
+            .map_err(|e| format!("Failed to get parallel_setup_cost: {}", e))?,
+        recommendation,
+    })
+}
+
+#[tauri::command]
+pub async fn get_pg_wait_events(config: ConnectionConfig) -> Result<Vec<WaitEvent>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Blocked sessions first; our own backend is excluded
+    let rows = sqlx::query(
+        "SELECT
+            pid,
+            wait_event_type,
+            wait_event,
+            COALESCE(state, '') AS state,
+            NULLIF(query, '') AS query,
+            pg_blocking_pids(pid) AS blocking_pids
+         FROM pg_stat_activity
+         WHERE wait_event_type IS NOT NULL
+           AND pid <> pg_backend_pid()
+         ORDER BY cardinality(pg_blocking_pids(pid)) DESC, pid",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch wait events: {}", e))?;
+
+    pool.close().await;
+
+    let mut events = Vec::new();
+    for row in rows {
+        events.push(WaitEvent {
+            pid: row
+                .try_get("pid")
+                .map_err(|e| format!("Failed to get pid: {}", e))?,
+            wait_event_type: row
+                .try_get("wait_event_type")
+                .map_err(|e| format!("Failed to get wait event type: {}", e))?,
+            wait_event: row
+                .try_get("wait_event")
+                .map_err(|e| format!("Failed to get wait event: {}", e))?,
+            state: row
+                .try_get("state")
+                .map_err(|e| format!("Failed to get state: {}", e))?,
+            query: row.try_get("query").ok().flatten(),
+            blocking_pids: row
+                .try_get("blocking_pids")
+                .map_err(|e| format!("Failed to get blocking pids: {}", e))?,
+        });
+    }
+
+    Ok(events)
+}
+
+#[tauri::command]
+pub async fn get_publication_tables(
+    config: ConnectionConfig,
+    publication_name: String,
+) -> Result<Vec<String>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    let tables: Vec<String> = sqlx::query_scalar(
+        "SELECT quote_ident(schemaname) || '.' || quote_ident(tablename)
+         FROM pg_publication_tables
+         WHERE pubname = $1
+         ORDER BY schemaname, tablename",
+    )
+    .bind(&publication_name)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch publication tables: {}", e))?;
+
+    pool.close().await;
+
+    Ok(tables)
+}
+
+#[tauri::command]
+pub async fn get_subscription_status(
+    config: ConnectionConfig,
+) -> Result<Vec<SubscriptionStatus>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Table sync workers also appear in pg_stat_subscription; only the apply worker has no relid
+    let rows = sqlx::query(
+        "SELECT
+            s.subname::text AS subname,
+            s.subenabled,
+            COALESCE(st.received_lsn::text, '') AS received_lsn,
+            COALESCE(st.latest_end_lsn::text, '') AS latest_end_lsn,
+            COALESCE(pg_wal_lsn_diff(st.received_lsn, st.latest_end_lsn), 0)::int8 AS lag_bytes
+         FROM pg_subscription s
+         LEFT JOIN pg_stat_subscription st ON st.subid = s.oid AND st.relid IS NULL
+         WHERE s.subdbid = (SELECT oid FROM pg_database WHERE datname = current_database())
+         ORDER BY s.subname",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch subscription status: {}", e))?;
+
+    pool.close().await;
+
+    let mut subscriptions = Vec::new();
+    for row in rows {
+        subscriptions.push(SubscriptionStatus {
+            subname: row
+                .try_get("subname")
+                .map_err(|e| format!("Failed to get subscription name: {}", e))?,
+            subenabled: row
+                .try_get("subenabled")
+                .map_err(|e| format!("Failed to get subscription enabled: {}", e))?,
+            received_lsn: row
+                .try_get("received_lsn")
+                .map_err(|e| format!("Failed to get received lsn: {}", e))?,
+            latest_end_lsn: row
+                .try_get("latest_end_lsn")
+                .map_err(|e| format!("Failed to get latest end lsn: {}", e))?,
+            lag_bytes: row
+                .try_get("lag_bytes")
+                .map_err(|e| format!("Failed to get lag bytes: {}", e))?,
+        });
+    }
+
+    Ok(subscriptions)
+}
+
+#[tauri::command]
+pub async fn get_pg_hba_rules(config: ConnectionConfig) -> Result<Vec<HbaRule>, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // Reflects pg_hba.conf as it is on disk, which may not be loaded yet
+    let rows = sqlx::query(
+        "SELECT
+            COALESCE(type, '') AS type,
+            array_to_string(database, ',') AS database,
+            array_to_string(user_name, ',') AS user_name,
+            address,
+            COALESCE(auth_method, '') AS auth_method
+         FROM pg_hba_file_rules
+         ORDER BY line_number",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        let code = e.as_database_error().and_then(|db_error| db_error.code());
+        if code.as_deref() == Some("42501") {
+            "pg_hba_file_rules view requires superuser".to_string()
+        } else {
+            format!("Failed to fetch pg_hba rules: {}", e)
+        }
+    })?;
+
+    pool.close().await;
+
+    let mut rules = Vec::new();
+    for row in rows {
+        rules.push(HbaRule {
+            type_: row
+                .try_get("type")
+                .map_err(|e| format!("Failed to get rule type: {}", e))?,
+            database: row
+                .try_get::<Option<String>, _>("database")
+                .map_err(|e| format!("Failed to get rule database: {}", e))?
+                .unwrap_or_default(),
+            user_name: row
+                .try_get::<Option<String>, _>("user_name")
+                .map_err(|e| format!("Failed to get rule user name: {}", e))?
+                .unwrap_or_default(),
+            address: row.try_get("address").ok().flatten(),
+            auth_method: row
+                .try_get("auth_method")
+                .map_err(|e| format!("Failed to get auth method: {}", e))?,
+        });
+    }
+
+    Ok(rules)
+}
@@ -0,0 +1,95 @@
+use crate::models::{ConnectionConfig, PoolStats, PreparedStatement, SslInfo};
+use crate::state::ConnectionPools;
+use crate::utils::connect_postgres;
+use sqlx::Row;
+use tauri::AppHandle;
+
+#[tauri::command]
+pub async fn get_prepared_statements(
+    app: AppHandle,
+    config: ConnectionConfig,
+    pools: tauri::State<'_, ConnectionPools>,
+) -> Result<Vec<PreparedStatement>, String> {
+    // Prepared statements are per-session; this is the session execute_query runs in
+    let pool = pools.get_or_connect(&app, &config).await?;
+
+    // from_sql leaves out the protocol-level statements SQLx caches on the connection
+    let rows = sqlx::query(
+        "SELECT
+            name,
+            statement,
+            parameter_types::text[] AS parameter_types
+         FROM pg_prepared_statements
+         WHERE from_sql
+         ORDER BY prepare_time",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch prepared statements: {}", e))?;
+
+    let mut statements = Vec::new();
+    for row in rows {
+        statements.push(PreparedStatement {
+            name: row
+                .try_get("name")
+                .map_err(|e| format!("Failed to get statement name: {}", e))?,
+            statement: row
+                .try_get("statement")
+                .map_err(|e| format!("Failed to get statement: {}", e))?,
+            parameter_types: row
+                .try_get("parameter_types")
+                .map_err(|e| format!("Failed to get parameter types: {}", e))?,
+        });
+    }
+
+    Ok(statements)
+}
+
+#[tauri::command]
+pub fn get_connection_pool_stats(
+    connection_name: String,
+    pools: tauri::State<'_, ConnectionPools>,
+) -> Result<PoolStats, String> {
+    pools.stats(&connection_name)
+}
+
+#[tauri::command]
+pub async fn get_connection_ssl_info(config: ConnectionConfig) -> Result<SslInfo, String> {
+    let pool = connect_postgres(&config).await?;
+
+    // pg_stat_ssl first appeared in PostgreSQL 9.5
+    let available: bool =
+        sqlx::query_scalar("SELECT to_regclass('pg_catalog.pg_stat_ssl') IS NOT NULL")
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| format!("Failed to check for pg_stat_ssl: {}", e))?;
+    if !available {
+        pool.close().await;
+        return Err(
+            "pg_stat_ssl is not available; it requires PostgreSQL 9.5 or later".to_string(),
+        );
+    }
+
+    // to_jsonb tolerates columns renamed (clientdn -> client_dn) or dropped (compression)
+    // in later releases
+    let ssl: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT to_jsonb(s) FROM pg_stat_ssl s WHERE s.pid = pg_backend_pid()")
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e| format!("Failed to fetch SSL info: {}", e))?;
+
+    pool.close().await;
+
+    let ssl = ssl.ok_or("pg_stat_ssl has no entry for this connection")?;
+    let text = |field: &str| ssl[field].as_str().map(String::from);
+
+    Ok(SslInfo {
+        ssl_in_use: ssl["ssl"].as_bool().unwrap_or(false),
+        ssl_version: text("version"),
+        ssl_cipher: text("cipher"),
+        ssl_compression: ssl["compression"].as_bool().unwrap_or(false),
+        client_cert_used: text("client_dn")
+            .or_else(|| text("clientdn"))
+            .is_some_and(|dn| !dn.is_empty()),
+    })
+}
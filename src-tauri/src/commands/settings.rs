@@ -1,4 +1,4 @@
-use crate::models::ConnectionConfig;
+use crate::models::{ConnectionConfig, ProjectPathChangedPayload, SettingsChangedPayload};
 use crate::storage::{
     delete_password_from_keychain, get_password_from_keychain, save_password_to_keychain,
 };
@@ -7,7 +7,20 @@ use crate::utils::{
     load_project_settings_internal, set_auto_connect_enabled_internal, set_last_connection_internal,
     set_project_path_internal, get_recent_projects_internal, remove_recent_project_internal, RecentProject,
     get_vim_mode_enabled_internal, set_vim_mode_enabled_internal,
+    get_history_retention_days_internal, set_history_retention_days_internal,
+    get_health_check_interval_secs_internal, set_health_check_interval_secs_internal,
 };
+use tauri::{AppHandle, Emitter};
+
+// Let the frontend know a persisted setting changed so it can refresh
+fn emit_settings_changed(app: &AppHandle, key: &str) {
+    let _ = app.emit(
+        "settings-changed",
+        SettingsChangedPayload {
+            key: key.to_string(),
+        },
+    );
+}
 
 #[tauri::command]
 pub fn get_app_dir() -> Result<std::path::PathBuf, String> {
@@ -15,8 +28,22 @@ pub fn get_app_dir() -> Result<std::path::PathBuf, String> {
 }
 
 #[tauri::command]
-pub fn set_project_path(path: String) -> Result<(), String> {
-    set_project_path_internal(path)
+pub fn set_project_path(app: AppHandle, path: String) -> Result<(), String> {
+    let previous_path = get_current_project_path_internal()?;
+    set_project_path_internal(path)?;
+
+    // History, saved queries and connections all live in the project directory
+    if let Some(new_path) = get_current_project_path_internal()? {
+        let _ = app.emit(
+            "project-path-changed",
+            ProjectPathChangedPayload {
+                new_path,
+                previous_path,
+            },
+        );
+    }
+
+    Ok(())
 }
 
 #[tauri::command]
@@ -25,14 +52,18 @@ pub fn get_current_project_path() -> Result<Option<String>, String> {
 }
 
 #[tauri::command]
-pub fn load_project_settings() -> Result<(), String> {
-    load_project_settings_internal()
+pub fn load_project_settings(app: AppHandle) -> Result<(), String> {
+    load_project_settings_internal()?;
+    emit_settings_changed(&app, "project_path");
+    Ok(())
 }
 
 #[tauri::command]
-pub fn save_connections(connections: Vec<ConnectionConfig>) -> Result<(), String> {
+pub fn save_connections(app: AppHandle, connections: Vec<ConnectionConfig>) -> Result<(), String> {
     let app_dir = crate::utils::get_app_dir()?;
-    crate::storage::save_connections(connections, app_dir)
+    crate::storage::save_connections(connections, app_dir)?;
+    emit_settings_changed(&app, "connections");
+    Ok(())
 }
 
 #[tauri::command]
@@ -57,8 +88,10 @@ pub fn load_connections() -> Result<Vec<ConnectionConfig>, String> {
 }
 
 #[tauri::command]
-pub fn set_last_connection(connection_name: String) -> Result<(), String> {
-    set_last_connection_internal(connection_name)
+pub fn set_last_connection(app: AppHandle, connection_name: String) -> Result<(), String> {
+    set_last_connection_internal(connection_name)?;
+    emit_settings_changed(&app, "last_connection");
+    Ok(())
 }
 
 #[tauri::command]
@@ -67,8 +100,10 @@ pub fn get_last_connection() -> Result<Option<String>, String> {
 }
 
 #[tauri::command]
-pub fn set_auto_connect_enabled(enabled: bool) -> Result<(), String> {
-    set_auto_connect_enabled_internal(enabled)
+pub fn set_auto_connect_enabled(app: AppHandle, enabled: bool) -> Result<(), String> {
+    set_auto_connect_enabled_internal(enabled)?;
+    emit_settings_changed(&app, "auto_connect_enabled");
+    Ok(())
 }
 
 #[tauri::command]
@@ -82,16 +117,47 @@ pub fn get_recent_projects() -> Result<Vec<RecentProject>, String> {
 }
 
 #[tauri::command]
-pub fn remove_recent_project(path: String) -> Result<(), String> {
-    remove_recent_project_internal(path)
+pub fn remove_recent_project(app: AppHandle, path: String) -> Result<(), String> {
+    remove_recent_project_internal(path)?;
+    emit_settings_changed(&app, "recent_projects");
+    Ok(())
 }
 
 #[tauri::command]
-pub fn set_vim_mode_enabled(enabled: bool) -> Result<(), String> {
-    set_vim_mode_enabled_internal(enabled)
+pub fn set_vim_mode_enabled(app: AppHandle, enabled: bool) -> Result<(), String> {
+    set_vim_mode_enabled_internal(enabled)?;
+    emit_settings_changed(&app, "vim_mode_enabled");
+    Ok(())
 }
 
 #[tauri::command]
 pub fn get_vim_mode_enabled() -> Result<bool, String> {
     get_vim_mode_enabled_internal()
 }
+
+#[tauri::command]
+pub fn set_history_retention_days(app: AppHandle, days: Option<u32>) -> Result<(), String> {
+    set_history_retention_days_internal(days)?;
+    emit_settings_changed(&app, "history_retention_days");
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_history_retention_days() -> Result<Option<u32>, String> {
+    get_history_retention_days_internal()
+}
+
+#[tauri::command]
+pub fn set_health_check_interval_secs(app: AppHandle, secs: u64) -> Result<(), String> {
+    if secs == 0 {
+        return Err("Health check interval must be at least 1 second".to_string());
+    }
+    set_health_check_interval_secs_internal(secs)?;
+    emit_settings_changed(&app, "health_check_interval_secs");
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_health_check_interval_secs() -> Result<Option<u64>, String> {
+    get_health_check_interval_secs_internal()
+}
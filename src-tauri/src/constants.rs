@@ -5,6 +5,7 @@ pub const HISTORY_DB_FILENAME: &str = "history.db";
 pub const SAVED_QUERIES_DB_FILENAME: &str = "saved_queries.db";
 pub const CONNECTIONS_FILENAME: &str = "connections.json";
 pub const SETTINGS_FILENAME: &str = "settings.json";
+pub const AUDIT_LOG_FILENAME: &str = "audit.log";
 
 // Directory names
 pub const APP_DIR_NAME: &str = ".query";
@@ -18,3 +19,41 @@ pub const SQL_NULLABLE_YES: &str = "YES";
 // Warning types for schema comparison
 pub const WARNING_TYPE_DATA_LOSS: &str = "data_loss";
 pub const WARNING_TYPE_BREAKING_CHANGE: &str = "breaking_change";
+
+// Maintenance thresholds
+pub const BLOAT_VACUUM_THRESHOLD: f64 = 0.5;
+pub const INDEX_RECOMMENDATION_MIN_SEQ_TUP_READ: i64 = 10_000;
+pub const SEQUENCE_AT_RISK_PERCENT: f64 = 90.0;
+
+// Column value sampling
+pub const MAX_UNIQUE_VALUES_LIMIT: u32 = 1000;
+
+// Table sampling (TABLESAMPLE percentage bounds)
+pub const MIN_TABLE_SAMPLE_PERCENT: f32 = 0.001;
+pub const MAX_TABLE_SAMPLE_PERCENT: f32 = 100.0;
+
+// Query benchmarking
+pub const MAX_BENCHMARK_ITERATIONS: u8 = 20;
+
+// Planner cost recommendations
+pub const SSD_RANDOM_PAGE_COST: f64 = 1.1;
+
+// Cache lifetimes
+pub const TYPE_MAP_CACHE_TTL_SECS: u64 = 600;
+pub const SCHEMA_CACHE_TTL_SECS: u64 = 120;
+
+// Rough per-row costs (in seconds) used for migration duration estimates
+pub const MIGRATION_COST_PER_ROW_REWRITE: f64 = 0.000_01;
+pub const MIGRATION_COST_PER_ROW_SCAN: f64 = 0.000_002;
+pub const MIGRATION_COST_PER_ROW_INDEX: f64 = 0.000_005;
+
+// SQL file execution
+pub const MAX_SQL_FILE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+
+// Audit log
+pub const AUDIT_QUERY_MAX_CHARS: usize = 1000;
+
+// Persistent connection pools; one pinned server session per connection so session
+// state such as prepared statements and SET carries over between queries
+pub const PERSISTENT_POOL_MAX_CONNECTIONS: u32 = 1;
+pub const CONNECTION_HEALTH_CHECK_INTERVAL_SECS: u64 = 30;
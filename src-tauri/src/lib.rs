@@ -2,6 +2,7 @@
 mod commands;
 mod constants;
 mod models;
+mod state;
 mod storage;
 mod utils;
 
@@ -65,29 +66,132 @@ pub fn run() {
 
             Ok(())
         })
+        .manage(state::TypeMapCache::default())
+        .manage(state::ConnectionPools::default())
+        .manage(state::SchemaCache::default())
+        .manage(state::SchemaWatchers::default())
+        .manage(state::AuditLogger::default())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .invoke_handler(tauri::generate_handler![
             greet,
+            // App commands
+            get_app_version,
+            // Audit commands
+            get_audit_log,
             // Connection commands
             test_postgres_connection,
+            get_connection_string_preview,
+            get_connection_from_env,
             execute_query,
+            run_query_in_all_schemas,
+            get_query_result_schema,
+            get_column_unique_values,
+            get_table_sample,
             get_database_schema,
             get_database_schemas,
+            get_system_catalog_tables,
+            get_default_schema_for_connection,
+            get_database_list,
             get_enhanced_database_schema,
+            clone_schema,
+            import_sql_file_and_execute,
+            apply_migration_script,
+            get_column_data_type_map,
+            get_domain_types,
+            get_composite_types,
+            get_function_signatures,
+            get_stored_procedure_source,
+            get_index_definition,
+            get_constraint_definition,
+            get_plpgsql_function_args,
+            get_routine_argument_defaults,
+            get_event_triggers,
+            get_inheritance_hierarchy,
+            get_cte_dependency_graph,
+            get_view_dependencies,
+            get_schema_change_impact_analysis,
+            get_schema_object_count,
+            get_all_schema_objects,
+            get_table_constraints_summary,
+            get_tablespace_per_table,
+            get_table_row_estimates,
+            get_storage_parameters,
+            get_schema_version,
+            // Export commands
+            get_schema_as_dot,
+            get_schema_as_mermaid,
+            get_schema_as_openapi_components,
+            get_schema_as_jsonschema,
+            get_schema_as_typescript_interfaces,
+            generate_er_diagram_json,
+            export_schema_to_sql,
+            get_create_table_ddl,
+            get_result_as_tsv,
+            get_result_as_markdown_table,
+            // Performance commands
+            get_pg_stat_statements,
+            get_bloat_estimate,
+            get_index_recommendations,
+            find_missing_indexes_on_foreign_keys,
+            test_query_performance,
+            get_query_cost_estimate,
+            explain_analyze_structured,
+            get_column_statistics_histogram,
+            suggest_query_optimizations,
+            // Maintenance commands
+            run_vacuum_analyze,
+            analyze_table,
+            get_unused_sequences,
+            get_sequences_current_values,
+            get_materialized_view_refresh_info,
+            refresh_materialized_view,
+            // Security commands
+            get_role_permissions,
+            get_row_level_security_policies,
+            check_connection_permissions,
+            // Server commands
+            get_pg_settings,
+            get_planner_settings,
+            get_pg_wait_events,
+            get_publication_tables,
+            get_subscription_status,
+            get_pg_hba_rules,
+            // Session commands
+            get_prepared_statements,
+            get_connection_pool_stats,
+            get_connection_ssl_info,
+            watch_schema_changes,
+            unwatch_schema_changes,
             // Comparison commands
             compare_schemas,
+            compare_schemas_summary,
             generate_migration_sql,
+            save_html_report,
+            save_csv_report,
+            compare_query_results,
+            estimate_migration_duration,
             // History commands
             save_query_to_history,
             get_query_history,
             clear_query_history,
+            get_query_history_by_connection,
+            clear_history_for_connection,
+            get_history_entry_by_id,
+            deduplicate_query_history,
+            get_query_history_timeline,
+            get_slowest_queries,
+            get_most_frequent_queries,
             // Saved queries commands
             save_query,
             get_saved_queries,
             delete_saved_query,
             toggle_pin_query,
+            rename_saved_query,
+            get_saved_query_by_id,
+            reorder_saved_queries,
+            save_query_from_history,
             // Settings commands
             set_project_path,
             get_current_project_path,
@@ -98,6 +202,10 @@ pub fn run() {
             set_auto_connect_enabled,
             get_auto_connect_enabled,
             set_vim_mode_enabled,
+            set_history_retention_days,
+            get_history_retention_days,
+            set_health_check_interval_secs,
+            get_health_check_interval_secs,
             get_vim_mode_enabled,
             get_recent_projects,
             remove_recent_project,
@@ -107,6 +215,8 @@ pub fn run() {
             save_connection_password,
             get_connection_password,
             delete_connection_password,
+            import_connections_from_pgpass,
+            import_connections_from_dbeaver,
             // Git commands
             check_git_repo,
             get_git_status,
@@ -115,6 +225,14 @@ pub fn run() {
             git_commit,
             git_push,
             git_pull,
+            git_cherry_pick,
+            git_reset,
+            git_set_user_config,
+            git_get_user_config,
+            git_get_diff_stat,
+            git_get_untracked_files,
+            git_add_file,
+            git_add_all,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AppVersion {
+    pub version: String,
+    pub build_date: Option<String>, // YYYY-MM-DD, set by build.rs
+}
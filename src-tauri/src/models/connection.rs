@@ -11,4 +11,57 @@ pub struct ConnectionConfig {
     pub password: String,
     #[serde(default, rename = "readOnly")]
     pub read_only: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>, // Schema to open by default
+    // Mode of a pooler such as PgBouncer in front of the server:
+    // 'single', 'session', 'transaction' or 'statement'
+    #[serde(default, rename = "poolMode", skip_serializing_if = "Option::is_none")]
+    pub pool_mode: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DatabaseInfo {
+    pub name: String,
+    pub owner: String,
+    pub encoding: String,
+    pub size_bytes: Option<i64>, // None when we lack CONNECT on the database
+    pub is_template: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PreparedStatement {
+    pub name: String,
+    pub statement: String,
+    pub parameter_types: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PoolStats {
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    pub max_size: u32,
+    pub wait_count: u32, // commands that found the session busy and had to queue
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SslInfo {
+    pub ssl_in_use: bool,
+    pub ssl_version: Option<String>,
+    pub ssl_cipher: Option<String>,
+    pub ssl_compression: bool, // always false on PostgreSQL 14+, which removed it
+    pub client_cert_used: bool,
+}
+
+// Payload of the "connection-lost" and "connection-restored" events
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConnectionHealthPayload {
+    pub connection_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ImportSummary {
+    pub imported: u32,
+    pub skipped: u32,
+    pub messages: Vec<String>, // why entries were skipped or imported without a password
 }
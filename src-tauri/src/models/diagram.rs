@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ErAttribute {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub is_pk: bool,
+    pub is_fk: bool,
+    pub is_nullable: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ErEntity {
+    pub name: String,
+    pub attributes: Vec<ErAttribute>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ErRelationship {
+    pub from: String,
+    pub to: String,
+    pub cardinality: String, // 'many-to-one' or 'one-to-one'
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ErDiagram {
+    pub entities: Vec<ErEntity>,
+    pub relationships: Vec<ErRelationship>,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MaintenanceResult {
+    pub duration_ms: u128,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnalyzeResult {
+    pub tables_analyzed: Vec<String>,
+    pub duration_ms: u128,
+    pub warnings: Vec<String>, // tables the server skipped or failed to analyze
+    pub notices: Vec<String>, // ANALYZE VERBOSE progress messages
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SequenceValue {
+    pub sequence_name: String,
+    pub current_value: i64, // start value until the first nextval()
+    pub max_value: i64,
+    pub percent_used: f64,
+    pub at_risk: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UnusedSequence {
+    pub sequence_name: String,
+    pub drop_statement: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatViewRefreshInfo {
+    pub view_name: String,
+    pub last_refresh: Option<String>, // PostgreSQL does not record refresh times, so this is None
+    pub is_populated: bool,
+    pub definition: String,
+}
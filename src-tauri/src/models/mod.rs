@@ -1,10 +1,41 @@
+mod app;
 mod connection;
+mod diagram;
+mod maintenance;
+mod performance;
 mod query;
 mod schema;
+mod security;
+mod server;
+mod settings;
 
-pub use connection::ConnectionConfig;
-pub use query::{QueryHistoryEntry, QueryResult, SavedQuery};
+pub use app::AppVersion;
+pub use connection::{
+    ConnectionConfig, ConnectionHealthPayload, DatabaseInfo, ImportSummary, PoolStats,
+    PreparedStatement, SslInfo,
+};
+pub use diagram::{ErAttribute, ErDiagram, ErEntity, ErRelationship};
+pub use maintenance::{
+    AnalyzeResult, MaintenanceResult, MatViewRefreshInfo, SequenceValue, UnusedSequence,
+};
+pub use performance::{
+    BloatInfo, ColumnHistogram, IndexRecommendation, MissingFkIndex, PerformanceStats, PlanNode,
+    QueryCostEstimate, StatStatement,
+};
+pub use query::{
+    AuditEntry, ColumnResultInfo, ExecutionSummary, FrequentQuery, MaskConfig,
+    MigrationApplicationResult, MigrationError, MigrationProgressPayload, QueryComparison,
+    QueryHistoryEntry, QueryResult, SavedQuery, SchemaQueryResult, TimelineBucket,
+};
 pub use schema::{
-    ColumnInfo, DatabaseSchema, ForeignKeyInfo, TableInfo,
-    EnhancedColumnInfo, EnhancedDatabaseSchema, EnhancedTableInfo, IndexInfo, RoutineInfo, ViewInfo,
+    CloneResult, ColumnInfo, CompositeAttribute, CompositeTypeInfo, ConstraintInfo, DatabaseSchema,
+    DependencyGraph, DomainInfo, EventTriggerInfo, ForeignKeyInfo, FunctionArg, TableInfo,
+    ArgumentDefault, EnhancedColumnInfo, EnhancedDatabaseSchema, EnhancedTableInfo, EnumColumnUsage,
+    EnumTypeInfo, IndexInfo,
+    ImpactAnalysis, InheritanceRelation, RlsPolicy, RoutineInfo, SchemaChangeNotification,
+    SchemaObject, SchemaObjectCount, SchemaVersion, StorageParam, TablespaceAssignment, TsOptions,
+    ViewDependencies, ViewInfo, WarningSeverity,
 };
+pub use security::{PermissionsReport, RolePermissions, TableGrant};
+pub use server::{HbaRule, PgSetting, PlannerSettings, SubscriptionStatus, WaitEvent};
+pub use settings::{ProjectPathChangedPayload, SettingsChangedPayload};
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StatStatement {
+    pub query: String,
+    pub calls: i64,
+    pub total_exec_time_ms: f64,
+    pub mean_exec_time_ms: f64,
+    pub rows: i64,
+    pub shared_blks_hit: i64,
+    pub shared_blks_read: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BloatInfo {
+    pub table_name: String,
+    pub bloat_ratio: f64,
+    pub wasted_bytes: i64,
+    pub is_index: bool,
+    pub object_name: String,
+    pub needs_vacuum: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IndexRecommendation {
+    pub table_name: String,
+    pub column_name: String,
+    pub seq_scan_count: i64,
+    pub seq_tup_read: i64,
+    pub recommendation: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PerformanceStats {
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub avg_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub samples: Vec<f64>, // in execution order
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueryCostEstimate {
+    pub startup_cost: f64,
+    pub total_cost: f64,
+    pub plan_rows: f64,
+    pub plan_width: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlanNode {
+    pub node_type: String,
+    pub startup_cost: f64,
+    pub total_cost: f64,
+    pub plan_rows: f64,
+    pub actual_rows: Option<f64>, // the actual_* fields are only set by EXPLAIN ANALYZE
+    pub actual_time_ms: Option<f64>,
+    pub actual_loops: Option<u32>,
+    pub relation_name: Option<String>,
+    pub children: Vec<PlanNode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ColumnHistogram {
+    pub bounds: Vec<serde_json::Value>, // values are text, since pg_stats stores them as anyarray
+    pub null_fraction: f64,
+    pub avg_width: i32,
+    pub most_common_values: Vec<serde_json::Value>,
+    pub most_common_freqs: Vec<f64>,
+    pub correlation: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MissingFkIndex {
+    pub table_name: String,
+    pub column_name: String, // comma-separated for multi-column foreign keys
+    pub references_table: String,
+    pub suggested_sql: String,
+}
@@ -8,6 +8,13 @@ pub struct QueryResult {
     pub execution_time_ms: u128,
 }
 
+/// Outcome of a query in one schema; exactly one of `result` and `error` is set
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SchemaQueryResult {
+    pub result: Option<QueryResult>,
+    pub error: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QueryHistoryEntry {
     pub id: i64,
@@ -18,6 +25,61 @@ pub struct QueryHistoryEntry {
     pub executed_at: String, // ISO timestamp
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FrequentQuery {
+    pub query: String,
+    pub execution_count: u32,
+    pub avg_execution_ms: f64,
+    pub last_executed: String, // ISO timestamp
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TimelineBucket {
+    pub bucket_start: String, // UTC, e.g. "2024-05-01T13:15:00Z"
+    pub query_count: u32,
+    pub avg_execution_ms: f64,
+    pub total_rows: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExecutionSummary {
+    pub statements_executed: u32,
+    pub rows_affected: u64,
+    pub duration_ms: u128,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MigrationError {
+    pub statement_index: u32, // 0-based position in the script
+    pub statement: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MigrationApplicationResult {
+    pub statements_executed: u32,
+    pub statements_skipped: u32, // not run because an earlier statement failed
+    pub errors: Vec<MigrationError>,
+    pub duration_ms: u128,
+}
+
+// Payload of the "migration-progress" event, emitted after each statement
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MigrationProgressPayload {
+    pub statement_index: u32,
+    pub total_statements: u32,
+    pub success: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditEntry {
+    pub timestamp: String, // ISO timestamp
+    pub connection_name: String,
+    pub username: String,
+    pub query: String, // Truncated to AUDIT_QUERY_MAX_CHARS
+    pub row_count: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SavedQuery {
     pub id: i64,
@@ -25,6 +87,32 @@ pub struct SavedQuery {
     pub query: String,
     pub description: Option<String>,
     pub is_pinned: bool,
+    #[serde(default)]
+    pub sort_order: i32,
     pub created_at: String, // ISO timestamp
     pub updated_at: String, // ISO timestamp
 }
+
+/// Columns whose values are replaced before results leave the backend
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MaskConfig {
+    pub columns: Vec<String>, // Matched case-insensitively against result headers
+    pub mask_char: char,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ColumnResultInfo {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_name: String,
+    pub nullable: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryComparison {
+    pub rows_only_in_source: Vec<Vec<serde_json::Value>>,
+    pub rows_only_in_target: Vec<Vec<serde_json::Value>>,
+    pub columns_match: bool,
+    pub row_count_source: usize,
+    pub row_count_target: usize,
+}
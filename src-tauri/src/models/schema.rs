@@ -53,6 +53,26 @@ pub struct RoutineInfo {
     pub routine_type: String, // 'FUNCTION' or 'PROCEDURE'
     pub definition: Option<String>,
     pub return_type: Option<String>,
+    #[serde(default)]
+    pub argument_types: Vec<String>,
+    #[serde(default)]
+    pub argument_names: Vec<String>,
+    #[serde(default)]
+    pub volatility: Option<String>, // 'VOLATILE', 'STABLE' or 'IMMUTABLE'
+    #[serde(default)]
+    pub argument_defaults: Vec<ArgumentDefault>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RlsPolicy {
+    pub policy_name: String,
+    pub table_name: String,
+    pub command: String, // 'ALL', 'SELECT', 'INSERT', 'UPDATE' or 'DELETE'
+    pub roles: Vec<String>,
+    pub using_expr: Option<String>,
+    pub check_expr: Option<String>,
+    #[serde(default)]
+    pub restrictive: bool, // AS RESTRICTIVE rather than the default AS PERMISSIVE
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -74,11 +94,200 @@ pub struct EnhancedTableInfo {
     pub columns: Vec<EnhancedColumnInfo>,
     pub foreign_keys: Vec<ForeignKeyInfo>,
     pub indexes: Vec<IndexInfo>,
+    #[serde(default)]
+    pub rls_enabled: bool,
+    #[serde(default)]
+    pub rls_policies: Vec<RlsPolicy>,
+    #[serde(default)]
+    pub parent_table: Option<String>,
+    #[serde(default)]
+    pub child_tables: Vec<String>,
+    #[serde(default)]
+    pub storage_options: Vec<StorageParam>, // from pg_class.reloptions
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StorageParam {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InheritanceRelation {
+    pub parent_table: String,
+    pub child_table: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DomainInfo {
+    pub domain_name: String,
+    pub base_type: String,
+    pub not_null: bool,
+    pub default: Option<String>,
+    pub constraints: Vec<String>, // e.g. "CHECK (VALUE > 0)"
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EnumTypeInfo {
+    pub enum_name: String,
+    pub values: Vec<String>, // in sort order
+    #[serde(default)]
+    pub columns: Vec<EnumColumnUsage>, // table columns of this type, in any schema
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EnumColumnUsage {
+    pub schema_name: String,
+    pub table_name: String,
+    pub column_name: String,
+    pub is_array: bool, // column type is an array of the enum
+    pub column_default: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompositeAttribute {
+    pub attribute_name: String,
+    pub data_type: String,
+    pub ordinal: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompositeTypeInfo {
+    pub type_name: String,
+    pub schema_name: String,
+    pub attributes: Vec<CompositeAttribute>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EventTriggerInfo {
+    pub trigger_name: String,
+    pub event: String,   // e.g. 'ddl_command_start', 'sql_drop'
+    pub enabled: String, // 'ORIGIN', 'REPLICA', 'ALWAYS' or 'DISABLED'
+    pub function_name: String,
+    #[serde(default)]
+    pub tags: Vec<String>, // WHEN TAG IN filter, e.g. 'CREATE TABLE'; empty fires for all
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EnhancedDatabaseSchema {
     pub tables: Vec<EnhancedTableInfo>,
     pub views: Vec<ViewInfo>,
     pub routines: Vec<RoutineInfo>,
+    #[serde(default)]
+    pub domains: Vec<DomainInfo>,
+    #[serde(default)]
+    pub enums: Vec<EnumTypeInfo>,
+    #[serde(default)]
+    pub composite_types: Vec<CompositeTypeInfo>,
+    #[serde(default)]
+    pub event_triggers: Vec<EventTriggerInfo>, // database-wide, not scoped to the schema
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CloneResult {
+    pub tables_created: u32,
+    pub indexes_created: u32, // including those behind primary key and unique constraints
+    pub foreign_keys_created: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DependencyGraph {
+    pub nodes: Vec<String>,
+    pub edges: Vec<(String, String)>, // (dependent, dependency)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ViewDependencies {
+    pub depends_on_tables: Vec<String>,
+    pub depends_on_views: Vec<String>,
+    pub used_by_views: Vec<String>,
+    pub used_by_routines: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum WarningSeverity {
+    High,
+    Medium,
+    Low,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImpactAnalysis {
+    pub views_affected: Vec<String>,
+    pub routines_affected: Vec<String>,
+    pub indexes_affected: Vec<String>,
+    pub risk_level: WarningSeverity,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConstraintInfo {
+    pub name: String,
+    pub constraint_type: String, // 'PRIMARY KEY', 'FOREIGN KEY', 'UNIQUE', 'CHECK', 'EXCLUDE'
+    pub columns: Vec<String>,
+    pub definition: String,
+    pub is_deferrable: bool,
+    pub is_validated: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunctionArg {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub mode: String, // 'IN', 'OUT', 'INOUT', 'VARIADIC' or 'TABLE'
+    pub default_value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ArgumentDefault {
+    pub position: i32, // 1-based, counting OUT arguments like pg_get_function_arg_default
+    pub argument_name: String,
+    pub default_expression: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SchemaVersion {
+    pub version: String,
+    pub description: String,
+    pub installed_on: String,
+    pub success: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SchemaObjectCount {
+    pub tables: u32,
+    pub views: u32,
+    pub routines: u32,
+    pub indexes: u32,
+    pub sequences: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SchemaObject {
+    pub name: String,
+    pub object_type: String, // 'table', 'view', 'function', 'procedure', 'sequence', 'index'
+    pub schema: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TablespaceAssignment {
+    pub table_name: String,
+    pub tablespace: String,
+    pub size_bytes: i64,
+}
+
+/// How `get_schema_as_typescript_interfaces` writes its output
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TsOptions {
+    pub use_nullable_union: bool, // `column: T | null` instead of `column?: T`
+    pub export_keyword: bool,
+    pub name_transform: String, // interface names: 'pascal', 'camel' or 'none'
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SchemaChangeNotification {
+    pub connection_name: String,
+    pub channel: String,
+    pub payload: String,
 }
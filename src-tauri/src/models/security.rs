@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TableGrant {
+    pub table_schema: String,
+    pub table_name: String,
+    pub privilege_type: String,
+    pub is_grantable: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RolePermissions {
+    pub table_grants: Vec<TableGrant>,
+    pub schema_grants: Vec<String>, // e.g. "public: USAGE"
+    pub is_superuser: bool,
+    pub can_login: bool,
+    pub connection_limit: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PermissionsReport {
+    pub can_select: bool,
+    pub can_insert: bool,
+    pub can_update: bool,
+    pub can_delete: bool,
+    pub can_create_table: bool,
+    pub is_superuser: bool,
+    pub schemas_accessible: Vec<String>,
+}
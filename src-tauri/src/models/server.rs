@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PgSetting {
+    pub name: String,
+    pub setting: String,
+    pub unit: Option<String>,
+    pub category: String,
+    pub short_desc: String,
+    pub source: String,
+    pub min_val: Option<String>,
+    pub max_val: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlannerSettings {
+    pub seq_page_cost: f64,
+    pub random_page_cost: f64,
+    pub cpu_tuple_cost: f64,
+    pub cpu_index_tuple_cost: f64,
+    pub effective_cache_size: String, // with unit, e.g. "4GB"
+    pub parallel_setup_cost: f64,
+    pub recommendation: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WaitEvent {
+    pub pid: i32,
+    pub wait_event_type: String,
+    pub wait_event: String,
+    pub state: String,
+    pub query: Option<String>,
+    pub blocking_pids: Vec<i32>, // empty unless blocked on a lock
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubscriptionStatus {
+    pub subname: String,
+    pub subenabled: bool,
+    pub received_lsn: String, // empty while the apply worker is not running
+    pub latest_end_lsn: String,
+    pub lag_bytes: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HbaRule {
+    #[serde(rename = "type")]
+    pub type_: String, // 'local', 'host', 'hostssl', ...
+    // Both lists are comma-separated
+    pub database: String,
+    pub user_name: String,
+    pub address: Option<String>, // None for local sockets
+    pub auth_method: String,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProjectPathChangedPayload {
+    pub new_path: String,
+    pub previous_path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SettingsChangedPayload {
+    pub key: String, // e.g. 'vim_mode_enabled', 'connections'
+}
@@ -0,0 +1,76 @@
+use crate::constants::{AUDIT_LOG_FILENAME, AUDIT_QUERY_MAX_CHARS};
+use crate::models::{AuditEntry, ConnectionConfig};
+use crate::utils::get_app_dir;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::Mutex;
+
+/// Appends one JSON line per data- or schema-modifying execution to `<app_dir>/audit.log`
+#[derive(Default)]
+pub struct AuditLogger {
+    // Serializes writers so concurrent commands never interleave lines
+    write_lock: Mutex<()>,
+}
+
+impl AuditLogger {
+    /// Records an execution; connections in read-only mode are not audited
+    pub fn record(
+        &self,
+        config: &ConnectionConfig,
+        query: &str,
+        row_count: u64,
+    ) -> Result<(), String> {
+        if config.read_only {
+            return Ok(());
+        }
+
+        let entry = AuditEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            connection_name: config.name.clone(),
+            username: config.username.clone(),
+            query: truncate_query(query),
+            row_count,
+        };
+        let line = serde_json::to_string(&entry)
+            .map_err(|e| format!("Failed to serialize audit entry: {}", e))?;
+
+        let _guard = self
+            .write_lock
+            .lock()
+            .map_err(|e| format!("Failed to acquire audit log lock: {}", e))?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_app_dir()?.join(AUDIT_LOG_FILENAME))
+            .map_err(|e| format!("Failed to open audit log: {}", e))?;
+
+        writeln!(file, "{}", line).map_err(|e| format!("Failed to write audit log: {}", e))
+    }
+
+    /// Returns up to `limit` of the most recent entries, newest first
+    pub fn read_recent(&self, limit: usize) -> Result<Vec<AuditEntry>, String> {
+        let path = get_app_dir()?.join(AUDIT_LOG_FILENAME);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read audit log: {}", e))?;
+
+        // Skip lines that fail to parse (e.g. a write cut short by a crash)
+        Ok(content
+            .lines()
+            .rev()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .take(limit)
+            .collect())
+    }
+}
+
+fn truncate_query(query: &str) -> String {
+    match query.char_indices().nth(AUDIT_QUERY_MAX_CHARS) {
+        Some((byte_index, _)) => format!("{}...", &query[..byte_index]),
+        None => query.to_string(),
+    }
+}
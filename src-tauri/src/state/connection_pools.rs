@@ -0,0 +1,235 @@
+use crate::constants::{CONNECTION_HEALTH_CHECK_INTERVAL_SECS, PERSISTENT_POOL_MAX_CONNECTIONS};
+use crate::models::{ConnectionConfig, ConnectionHealthPayload, PoolStats};
+use crate::utils::{get_health_check_interval_secs_internal, pg_connect_options};
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter};
+
+type PoolMap = Arc<Mutex<HashMap<String, PooledConnection>>>;
+
+struct PooledConnection {
+    // Connection settings the pool was opened with, to detect edited configs
+    fingerprint: String,
+    pool: PgPool,
+    // Commands handed the pool while its connection was in use
+    wait_count: AtomicU32,
+    // Background health check for this pool
+    monitor: JoinHandle<()>,
+}
+
+/// Long-lived PostgreSQL sessions keyed by connection name. Query commands run on
+/// them so later commands see the state earlier ones left behind
+#[derive(Default)]
+pub struct ConnectionPools {
+    pools: PoolMap,
+}
+
+impl ConnectionPools {
+    /// Returns the persistent pool for a connection, opening it and starting its
+    /// health check on first use
+    pub async fn get_or_connect(
+        &self,
+        app: &AppHandle,
+        config: &ConnectionConfig,
+    ) -> Result<PgPool, String> {
+        let fingerprint = connection_fingerprint(config);
+
+        if let Some(pool) = self.get_matching(&config.name, &fingerprint)? {
+            return Ok(pool);
+        }
+
+        let pool = open_pool(config).await?;
+
+        let (existing, previous) = {
+            let mut pools = self
+                .pools
+                .lock()
+                .map_err(|e| format!("Failed to acquire connection pool lock: {}", e))?;
+
+            // A concurrent first call got there while we were connecting; use its pool
+            // rather than replace one its caller is already running queries on
+            if let Some(pooled) = pools
+                .get(&config.name)
+                .filter(|pooled| pooled.fingerprint == fingerprint && !pooled.pool.is_closed())
+            {
+                (Some(pooled.pool.clone()), None)
+            } else {
+                let monitor = tauri::async_runtime::spawn(monitor_health(
+                    app.clone(),
+                    self.pools.clone(),
+                    config.clone(),
+                ));
+                let previous = pools.insert(
+                    config.name.clone(),
+                    PooledConnection {
+                        fingerprint,
+                        pool: pool.clone(),
+                        wait_count: AtomicU32::new(0),
+                        monitor,
+                    },
+                );
+                (None, previous)
+            }
+        };
+
+        if let Some(existing) = existing {
+            pool.close().await;
+            return Ok(existing);
+        }
+
+        // The config was edited since the pool was opened
+        if let Some(previous) = previous {
+            previous.monitor.abort();
+            previous.pool.close().await;
+        }
+
+        Ok(pool)
+    }
+
+    /// Reports pool usage for a connection that has a persistent pool open
+    pub fn stats(&self, name: &str) -> Result<PoolStats, String> {
+        let pools = self
+            .pools
+            .lock()
+            .map_err(|e| format!("Failed to acquire connection pool lock: {}", e))?;
+        let pooled = pools
+            .get(name)
+            .ok_or_else(|| format!("No persistent pool open for '{}'", name))?;
+
+        // SQLx's idle counter can briefly underflow while a connection is handed back
+        let size = pooled.pool.size();
+        let idle = u32::try_from(pooled.pool.num_idle()).map_or(0, |idle| idle.min(size));
+        Ok(PoolStats {
+            size,
+            idle,
+            in_use: size.saturating_sub(idle),
+            max_size: pooled.pool.options().get_max_connections(),
+            wait_count: pooled.wait_count.load(Ordering::Relaxed),
+        })
+    }
+
+    fn get_matching(&self, name: &str, fingerprint: &str) -> Result<Option<PgPool>, String> {
+        let pools = self
+            .pools
+            .lock()
+            .map_err(|e| format!("Failed to acquire connection pool lock: {}", e))?;
+
+        let Some(pooled) = pools
+            .get(name)
+            .filter(|pooled| pooled.fingerprint == fingerprint && !pooled.pool.is_closed())
+        else {
+            return Ok(None);
+        };
+
+        // The caller will queue behind whatever holds the connection
+        if pooled.pool.size() > 0 && pooled.pool.num_idle() == 0 {
+            pooled.wait_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(Some(pooled.pool.clone()))
+    }
+}
+
+async fn open_pool(config: &ConnectionConfig) -> Result<PgPool, String> {
+    // Never recycle the connection, or its session state would silently vanish
+    PgPoolOptions::new()
+        .max_connections(PERSISTENT_POOL_MAX_CONNECTIONS)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect_with(pg_connect_options(config))
+        .await
+        .map_err(|e| format!("Connection failed: {}", e))
+}
+
+// Runs `SELECT 1` on an interval, emitting "connection-lost" when the server stops
+// answering and "connection-restored" once a fresh pool can reach it again
+async fn monitor_health(app: AppHandle, pools: PoolMap, config: ConnectionConfig) {
+    let mut healthy = true;
+
+    loop {
+        let interval_secs = get_health_check_interval_secs_internal()
+            .ok()
+            .flatten()
+            .unwrap_or(CONNECTION_HEALTH_CHECK_INTERVAL_SECS);
+        tokio::time::sleep(Duration::from_secs(interval_secs)).await;
+
+        let current = pools
+            .lock()
+            .ok()
+            .and_then(|pools| pools.get(&config.name).map(|pooled| pooled.pool.clone()));
+        let Some(current) = current else {
+            return;
+        };
+
+        if healthy {
+            // A query is holding the session's only connection; waiting behind it would
+            // time out and report a busy server as lost
+            if current.size() > 0 && current.num_idle() == 0 {
+                continue;
+            }
+            if sqlx::query("SELECT 1").execute(&current).await.is_err() {
+                healthy = false;
+                emit_health_event(&app, "connection-lost", &config.name);
+            }
+            continue;
+        }
+
+        // Rebuild rather than reuse the old pool so no half-dead sessions linger
+        let Ok(pool) = open_pool(&config).await else {
+            continue;
+        };
+        if sqlx::query("SELECT 1").execute(&pool).await.is_err() {
+            pool.close().await;
+            continue;
+        }
+
+        let replaced = match pools.lock() {
+            Ok(mut pools) => pools
+                .get_mut(&config.name)
+                .map(|pooled| std::mem::replace(&mut pooled.pool, pool.clone())),
+            Err(_) => None,
+        };
+        match replaced {
+            Some(old) => old.close().await,
+            None => {
+                pool.close().await;
+                return;
+            }
+        }
+
+        healthy = true;
+        emit_health_event(&app, "connection-restored", &config.name);
+    }
+}
+
+fn emit_health_event(app: &AppHandle, event: &str, connection_name: &str) {
+    let _ = app.emit(
+        event,
+        ConnectionHealthPayload {
+            connection_name: connection_name.to_string(),
+        },
+    );
+}
+
+fn connection_fingerprint(config: &ConnectionConfig) -> String {
+    // Only a hash of the password, so it is not kept around as yet another plain string
+    let mut password_hash = DefaultHasher::new();
+    config.password.hash(&mut password_hash);
+
+    format!(
+        "{}:{:x}@{}:{}/{}?schema={}&pool_mode={}",
+        config.username,
+        password_hash.finish(),
+        config.host,
+        config.port,
+        config.database,
+        config.schema.as_deref().unwrap_or_default(),
+        config.pool_mode.as_deref().unwrap_or_default()
+    )
+}
@@ -0,0 +1,11 @@
+mod audit_logger;
+mod connection_pools;
+mod schema_cache;
+mod schema_watchers;
+mod type_cache;
+
+pub use audit_logger::AuditLogger;
+pub use connection_pools::ConnectionPools;
+pub use schema_cache::SchemaCache;
+pub use schema_watchers::{watcher_key, SchemaWatchers};
+pub use type_cache::TypeMapCache;
@@ -0,0 +1,29 @@
+use crate::models::EnhancedDatabaseSchema;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+type CachedSchema = (Instant, EnhancedDatabaseSchema);
+
+/// Per-connection, per-schema cache of fetched `EnhancedDatabaseSchema`s
+#[derive(Default)]
+pub struct SchemaCache {
+    entries: Mutex<HashMap<String, CachedSchema>>,
+}
+
+impl SchemaCache {
+    /// Returns the cached schema if it is younger than `ttl`
+    pub fn get(&self, schema_key: &str, ttl: Duration) -> Option<EnhancedDatabaseSchema> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(schema_key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < ttl)
+            .map(|(_, schema)| schema.clone())
+    }
+
+    pub fn insert(&self, schema_key: String, schema: EnhancedDatabaseSchema) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(schema_key, (Instant::now(), schema));
+        }
+    }
+}
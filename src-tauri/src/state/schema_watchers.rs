@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::async_runtime::JoinHandle;
+
+/// Background LISTEN tasks keyed by connection name and channel
+#[derive(Default)]
+pub struct SchemaWatchers {
+    tasks: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl SchemaWatchers {
+    /// Registers a listener task, stopping any previous task for the same key
+    pub fn insert(&self, key: String, task: JoinHandle<()>) -> Result<(), String> {
+        let previous = self
+            .tasks
+            .lock()
+            .map_err(|e| format!("Failed to acquire schema watcher lock: {}", e))?
+            .insert(key, task);
+
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+
+        Ok(())
+    }
+
+    /// Stops a listener task, returning whether one was running
+    pub fn remove(&self, key: &str) -> Result<bool, String> {
+        let task = self
+            .tasks
+            .lock()
+            .map_err(|e| format!("Failed to acquire schema watcher lock: {}", e))?
+            .remove(key);
+
+        Ok(match task {
+            Some(task) => {
+                task.abort();
+                true
+            }
+            None => false,
+        })
+    }
+}
+
+pub fn watcher_key(connection_name: &str, channel: &str) -> String {
+    format!("{}/{}", connection_name, channel)
+}
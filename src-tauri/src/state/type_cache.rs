@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+type CachedTypeMap = (Instant, HashMap<u32, String>);
+
+/// Per-connection cache of `pg_type` OID -> type name mappings
+#[derive(Default)]
+pub struct TypeMapCache {
+    entries: Mutex<HashMap<String, CachedTypeMap>>,
+}
+
+impl TypeMapCache {
+    /// Returns the cached mapping for a connection if it is younger than `ttl`
+    pub fn get(&self, connection_key: &str, ttl: Duration) -> Option<HashMap<u32, String>> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(connection_key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < ttl)
+            .map(|(_, type_map)| type_map.clone())
+    }
+
+    pub fn insert(&self, connection_key: String, type_map: HashMap<u32, String>) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(connection_key, (Instant::now(), type_map));
+        }
+    }
+}
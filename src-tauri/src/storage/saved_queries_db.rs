@@ -31,5 +31,21 @@ pub async fn get_saved_queries_db(app_dir: std::path::PathBuf) -> Result<SqliteP
     .await
     .map_err(|e| format!("Failed to create table: {}", e))?;
 
+    // Databases created before manual ordering existed lack the sort_order column
+    let has_sort_order = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM pragma_table_info('saved_queries') WHERE name = 'sort_order'",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("Failed to inspect table: {}", e))?
+        > 0;
+
+    if !has_sort_order {
+        sqlx::query("ALTER TABLE saved_queries ADD COLUMN sort_order INTEGER NOT NULL DEFAULT 0")
+            .execute(&pool)
+            .await
+            .map_err(|e| format!("Failed to migrate table: {}", e))?;
+    }
+
     Ok(pool)
 }
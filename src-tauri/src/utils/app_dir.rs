@@ -229,6 +229,57 @@ pub fn get_vim_mode_enabled_internal() -> Result<bool, String> {
     Ok(settings.get("vim_mode_enabled").and_then(|v| v.as_bool()).unwrap_or(false))
 }
 
+/// `None` removes the setting so history is kept forever
+pub fn set_history_retention_days_internal(days: Option<u32>) -> Result<(), String> {
+    let settings_file = get_settings_file()?;
+    let mut settings = load_settings_json(&settings_file)?;
+    match days {
+        Some(days) => settings["history_retention_days"] = serde_json::json!(days),
+        None => {
+            if let Some(settings) = settings.as_object_mut() {
+                settings.remove("history_retention_days");
+            }
+        }
+    }
+
+    let json_str = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    fs::write(settings_file, json_str)
+        .map_err(|e| format!("Could not write settings: {}", e))?;
+
+    Ok(())
+}
+
+pub fn get_history_retention_days_internal() -> Result<Option<u32>, String> {
+    let settings_file = get_settings_file()?;
+    let settings = load_settings_json(&settings_file)?;
+    Ok(settings
+        .get("history_retention_days")
+        .and_then(|v| v.as_u64())
+        .map(|d| d as u32))
+}
+
+pub fn set_health_check_interval_secs_internal(secs: u64) -> Result<(), String> {
+    let settings_file = get_settings_file()?;
+    let mut settings = load_settings_json(&settings_file)?;
+    settings["health_check_interval_secs"] = serde_json::json!(secs);
+
+    let json_str = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    fs::write(settings_file, json_str)
+        .map_err(|e| format!("Could not write settings: {}", e))?;
+
+    Ok(())
+}
+
+pub fn get_health_check_interval_secs_internal() -> Result<Option<u64>, String> {
+    let settings_file = get_settings_file()?;
+    let settings = load_settings_json(&settings_file)?;
+    Ok(settings
+        .get("health_check_interval_secs")
+        .and_then(|v| v.as_u64()))
+}
+
 // Recent projects management
 
 const MAX_RECENT_PROJECTS: usize = 10;
@@ -0,0 +1,212 @@
+use crate::constants::SQL_NULLABLE_YES;
+use crate::models::{EnhancedColumnInfo, EnhancedDatabaseSchema, EnhancedTableInfo, TsOptions};
+use serde_json::{json, Map, Value};
+
+/// Build an OpenAPI `components` object with one schema per table
+pub fn render_schema_as_openapi_components(schema: &EnhancedDatabaseSchema) -> Value {
+    let mut schemas = Map::new();
+
+    for table in &schema.tables {
+        let mut properties = Map::new();
+        for column in &table.columns {
+            let mut property = json_schema_property(column);
+            if column.is_nullable == SQL_NULLABLE_YES {
+                property.insert("nullable".to_string(), Value::Bool(true));
+            }
+            if column.is_primary_key {
+                property.insert("x-primary-key".to_string(), Value::Bool(true));
+            }
+            properties.insert(column.column_name.clone(), Value::Object(property));
+        }
+
+        let mut table_schema = Map::new();
+        table_schema.insert("type".to_string(), json!("object"));
+        table_schema.insert("properties".to_string(), Value::Object(properties));
+        let required = required_columns(table);
+        if !required.is_empty() {
+            table_schema.insert("required".to_string(), json!(required));
+        }
+
+        schemas.insert(table.table_name.clone(), Value::Object(table_schema));
+    }
+
+    json!({ "schemas": schemas })
+}
+
+/// Build a standalone JSON Schema document describing one row of the table
+pub fn render_table_as_json_schema(table: &EnhancedTableInfo) -> Value {
+    let mut properties = Map::new();
+    for column in &table.columns {
+        let mut property = json_schema_property(column);
+        // int32, int64, float and double are OpenAPI formats, not JSON Schema ones
+        if property
+            .get("format")
+            .is_some_and(|f| !matches!(f.as_str(), Some("date" | "date-time" | "time" | "uuid")))
+        {
+            property.remove("format");
+        }
+        if column.is_nullable == SQL_NULLABLE_YES {
+            if let Some(json_type) = property.remove("type") {
+                property.insert("type".to_string(), json!([json_type, "null"]));
+            }
+        }
+        properties.insert(column.column_name.clone(), Value::Object(property));
+    }
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": table.table_name,
+        "type": "object",
+        "properties": properties,
+        "required": required_columns(table),
+    })
+}
+
+/// Render one TypeScript interface per table, named from the table via `name_transform`
+pub fn render_schema_as_typescript(schema: &EnhancedDatabaseSchema, options: &TsOptions) -> String {
+    let mut ts = String::new();
+
+    for (index, table) in schema.tables.iter().enumerate() {
+        if index > 0 {
+            ts.push('\n');
+        }
+        if options.export_keyword {
+            ts.push_str("export ");
+        }
+        ts.push_str(&format!(
+            "interface {} {{\n",
+            interface_name(&table.table_name, &options.name_transform)
+        ));
+
+        for column in &table.columns {
+            let nullable = column.is_nullable == SQL_NULLABLE_YES;
+            let name = ts_property_name(&column.column_name);
+            let ts_type = ts_type(column);
+            if nullable && options.use_nullable_union {
+                ts.push_str(&format!("  {}: {} | null;\n", name, ts_type));
+            } else if nullable {
+                ts.push_str(&format!("  {}?: {};\n", name, ts_type));
+            } else {
+                ts.push_str(&format!("  {}: {};\n", name, ts_type));
+            }
+        }
+
+        ts.push_str("}\n");
+    }
+
+    ts
+}
+
+fn ts_type(column: &EnhancedColumnInfo) -> &'static str {
+    match column.data_type.as_str() {
+        "smallint" | "integer" | "real" | "double precision" => "number",
+        // Drivers return these as strings since they can exceed Number precision
+        "bigint" | "numeric" | "money" => "string",
+        "boolean" => "boolean",
+        "json" | "jsonb" => "unknown",
+        "ARRAY" => "unknown[]",
+        _ => "string",
+    }
+}
+
+fn interface_name(table_name: &str, name_transform: &str) -> String {
+    let words: Vec<&str> = table_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let name = match name_transform {
+        "pascal" | "camel" => {
+            let mut name: String = words.iter().map(|word| capitalize(word)).collect();
+            if name_transform == "camel" {
+                if let Some(first) = name.get(..1) {
+                    name = first.to_ascii_lowercase() + &name[1..];
+                }
+            }
+            name
+        }
+        _ => words.join("_"),
+    };
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+// Column names that are not valid identifiers are written as string keys
+fn ts_property_name(column_name: &str) -> String {
+    let is_identifier = column_name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && column_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        column_name.to_string()
+    } else {
+        json!(column_name).to_string()
+    }
+}
+
+// Columns the database will not fill in on its own
+fn required_columns(table: &EnhancedTableInfo) -> Vec<&str> {
+    table
+        .columns
+        .iter()
+        .filter(|c| c.is_nullable != SQL_NULLABLE_YES && c.column_default.is_none())
+        .map(|c| c.column_name.as_str())
+        .collect()
+}
+
+// `type` plus `format` or `maxLength` where the PostgreSQL type pins one down
+fn json_schema_property(column: &EnhancedColumnInfo) -> Map<String, Value> {
+    let (json_type, format) = match column.data_type.as_str() {
+        "smallint" | "integer" => (Some("integer"), Some("int32")),
+        "bigint" => (Some("integer"), Some("int64")),
+        "real" => (Some("number"), Some("float")),
+        "double precision" => (Some("number"), Some("double")),
+        "numeric" => (Some("number"), None),
+        "boolean" => (Some("boolean"), None),
+        "uuid" => (Some("string"), Some("uuid")),
+        "date" => (Some("string"), Some("date")),
+        "timestamp without time zone" | "timestamp with time zone" => {
+            (Some("string"), Some("date-time"))
+        }
+        "time without time zone" | "time with time zone" => (Some("string"), Some("time")),
+        "ARRAY" => (Some("array"), None),
+        // Any JSON value is valid
+        "json" | "jsonb" => (None, None),
+        _ => (Some("string"), None),
+    };
+
+    let mut property = Map::new();
+    if let Some(json_type) = json_type {
+        property.insert("type".to_string(), json!(json_type));
+    }
+    if let Some(format) = format {
+        property.insert("format".to_string(), json!(format));
+    }
+    if json_type == Some("array") {
+        // information_schema does not report the element type
+        property.insert("items".to_string(), json!({}));
+    }
+    if let ("character varying" | "character", Some(length)) =
+        (column.data_type.as_str(), column.character_maximum_length)
+    {
+        property.insert("maxLength".to_string(), json!(length));
+    }
+
+    property
+}
@@ -0,0 +1,217 @@
+use crate::constants::SQL_NULLABLE_YES;
+use crate::models::{
+    EnhancedDatabaseSchema, EnhancedTableInfo, ErAttribute, ErDiagram, ErEntity, ErRelationship,
+};
+use std::collections::HashSet;
+
+/// Render the schema as a Graphviz DOT digraph with one node per table and an edge per FK
+pub fn render_schema_as_dot(schema: &EnhancedDatabaseSchema, rankdir: &str) -> String {
+    let mut dot = String::new();
+
+    dot.push_str("digraph schema {\n");
+    dot.push_str(&format!("  rankdir={};\n", rankdir));
+    dot.push_str("  node [shape=plaintext, fontname=\"Helvetica\"];\n");
+    dot.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n\n");
+
+    for table in &schema.tables {
+        dot.push_str(&format!(
+            "  \"{}\" [label=<\n    <table border=\"0\" cellborder=\"1\" cellspacing=\"0\">\n",
+            escape_dot_id(&table.table_name)
+        ));
+        dot.push_str(&format!(
+            "      <tr><td bgcolor=\"lightgrey\"><b>{}</b></td></tr>\n",
+            escape_html(&table.table_name)
+        ));
+
+        for column in &table.columns {
+            let marker = if column.is_primary_key { " (PK)" } else { "" };
+            dot.push_str(&format!(
+                "      <tr><td port=\"{}\" align=\"left\">{} : {}{}</td></tr>\n",
+                escape_html(&column.column_name),
+                escape_html(&column.column_name),
+                escape_html(&column.data_type),
+                marker
+            ));
+        }
+
+        dot.push_str("    </table>\n  >];\n");
+    }
+
+    dot.push('\n');
+
+    for table in &schema.tables {
+        for fk in &table.foreign_keys {
+            dot.push_str(&format!(
+                "  \"{}\":\"{}\" -> \"{}\":\"{}\" [label=\"{}\"];\n",
+                escape_dot_id(&fk.table_name),
+                escape_dot_id(&fk.column_name),
+                escape_dot_id(&fk.foreign_table_name),
+                escape_dot_id(&fk.foreign_column_name),
+                escape_dot_id(&fk.constraint_name)
+            ));
+        }
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+/// Render the schema as a Mermaid `erDiagram` with one `||--o{` relationship per FK constraint
+pub fn render_schema_as_mermaid(schema: &EnhancedDatabaseSchema) -> String {
+    let mut mermaid = String::from("erDiagram\n");
+
+    for table in &schema.tables {
+        let fk_columns: HashSet<&str> = table
+            .foreign_keys
+            .iter()
+            .map(|fk| fk.column_name.as_str())
+            .collect();
+
+        mermaid.push_str(&format!("    {} {{\n", mermaid_id(&table.table_name)));
+        for column in &table.columns {
+            let keys = match (
+                column.is_primary_key,
+                fk_columns.contains(column.column_name.as_str()),
+            ) {
+                (true, true) => " PK, FK",
+                (true, false) => " PK",
+                (false, true) => " FK",
+                (false, false) => "",
+            };
+            mermaid.push_str(&format!(
+                "        {} {}{}\n",
+                mermaid_id(&column.data_type),
+                mermaid_id(&column.column_name),
+                keys
+            ));
+        }
+        mermaid.push_str("    }\n");
+    }
+
+    for table in &schema.tables {
+        let mut seen_constraints = HashSet::new();
+        for fk in &table.foreign_keys {
+            if !seen_constraints.insert(fk.constraint_name.as_str()) {
+                continue;
+            }
+            mermaid.push_str(&format!(
+                "    {} ||--o{{ {} : \"{}\"\n",
+                mermaid_id(&fk.foreign_table_name),
+                mermaid_id(&fk.table_name),
+                fk.constraint_name.replace('"', "'")
+            ));
+        }
+    }
+
+    mermaid
+}
+
+/// Build a normalized ER diagram with one entity per table and one relationship per FK constraint
+pub fn build_er_diagram(schema: &EnhancedDatabaseSchema) -> ErDiagram {
+    let mut entities = Vec::new();
+    let mut relationships = Vec::new();
+
+    for table in &schema.tables {
+        let fk_columns: HashSet<&str> = table
+            .foreign_keys
+            .iter()
+            .map(|fk| fk.column_name.as_str())
+            .collect();
+
+        entities.push(ErEntity {
+            name: table.table_name.clone(),
+            attributes: table
+                .columns
+                .iter()
+                .map(|column| ErAttribute {
+                    name: column.column_name.clone(),
+                    type_: column.data_type.clone(),
+                    is_pk: column.is_primary_key,
+                    is_fk: fk_columns.contains(column.column_name.as_str()),
+                    is_nullable: column.is_nullable == SQL_NULLABLE_YES,
+                })
+                .collect(),
+        });
+
+        // Multi-column FKs appear once per column; keep one relationship per constraint
+        let mut seen_constraints = HashSet::new();
+        for fk in &table.foreign_keys {
+            if !seen_constraints.insert(fk.constraint_name.as_str()) {
+                continue;
+            }
+
+            let constraint_columns: Vec<&str> = table
+                .foreign_keys
+                .iter()
+                .filter(|other| other.constraint_name == fk.constraint_name)
+                .map(|other| other.column_name.as_str())
+                .collect();
+
+            let cardinality = if is_unique_key(table, &constraint_columns) {
+                "one-to-one"
+            } else {
+                "many-to-one"
+            };
+
+            relationships.push(ErRelationship {
+                from: fk.table_name.clone(),
+                to: fk.foreign_table_name.clone(),
+                cardinality: cardinality.to_string(),
+            });
+        }
+    }
+
+    ErDiagram {
+        entities,
+        relationships,
+    }
+}
+
+// Whether the columns exactly match the table's primary key or a unique index
+fn is_unique_key(table: &EnhancedTableInfo, columns: &[&str]) -> bool {
+    let matches = |key: &[String]| {
+        key.len() == columns.len() && key.iter().all(|c| columns.contains(&c.as_str()))
+    };
+
+    let primary_key: Vec<String> = table
+        .columns
+        .iter()
+        .filter(|c| c.is_primary_key)
+        .map(|c| c.column_name.clone())
+        .collect();
+
+    matches(&primary_key)
+        || table
+            .indexes
+            .iter()
+            .any(|index| index.is_unique && matches(&index.columns))
+}
+
+// Mermaid identifiers and attribute types cannot contain spaces or punctuation
+fn mermaid_id(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+// Escape a value for use inside a double-quoted DOT identifier
+fn escape_dot_id(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Escape a value for use inside a DOT HTML-like label
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,15 +1,44 @@
 mod app_dir;
+mod code_gen;
+mod diagram;
+mod notices;
+mod postgres;
+mod query_analysis;
+mod result_format;
 pub mod schema_diff;
+mod sql_export;
+mod sql_script;
 
 pub use app_dir::{
     get_app_dir, get_auto_connect_enabled_internal, get_current_project_path_internal,
     get_last_connection_internal, load_project_settings_internal, set_auto_connect_enabled_internal,
     set_last_connection_internal, set_project_path_internal, get_recent_projects_internal,
     remove_recent_project_internal, RecentProject, get_vim_mode_enabled_internal,
-    set_vim_mode_enabled_internal,
+    set_vim_mode_enabled_internal, get_history_retention_days_internal,
+    set_history_retention_days_internal, get_health_check_interval_secs_internal,
+    set_health_check_interval_secs_internal,
 };
 
+pub use code_gen::{
+    render_schema_as_openapi_components, render_schema_as_typescript, render_table_as_json_schema,
+};
+
+pub use diagram::{build_er_diagram, render_schema_as_dot, render_schema_as_mermaid};
+
+pub use postgres::{
+    connect_postgres, connection_string_preview, execute_batch, pg_connect_options, pg_value_to_json,
+    qualified_name, quote_identifier, quote_literal, uses_transaction_pooling,
+};
+
+pub use notices::capture_notices;
+
+pub use query_analysis::{suggest_optimizations, QuerySuggestion};
+pub use result_format::{format_result_as_markdown_table, format_result_as_tsv};
+pub use sql_export::{render_schema_as_sql, render_table_as_sql, SqlExportCatalog};
+pub use sql_script::{split_sql_script, statement_words};
+
 pub use schema_diff::{
-    compare_schemas, generate_migration_script,
-    SchemaComparison,
+    compare_schemas, estimate_migration, generate_csv_report, generate_html_report,
+    generate_migration_script, summarize_fingerprints, tables_needing_row_counts, ComparisonSummary,
+    MigrationEstimate, SchemaComparison, SchemaFingerprints,
 };
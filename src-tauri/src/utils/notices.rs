@@ -0,0 +1,82 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::instrument::WithSubscriber;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+// SQLx has no notice callback; it reports each notice as a tracing event on this target
+const NOTICE_TARGET: &str = "sqlx::postgres::notice";
+
+/// A NOTICE, INFO, WARNING, ... message the server sent while a statement ran
+pub struct ServerNotice {
+    pub is_warning: bool, // WARNING or higher
+    pub message: String,
+}
+
+/// Runs `future` and returns the notices its queries received. Collection is scoped to
+/// this future, so run the queries on a connection it acquires itself.
+pub async fn capture_notices<F: Future>(future: F) -> (F::Output, Vec<ServerNotice>) {
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let output = future
+        .with_subscriber(NoticeCollector {
+            notices: notices.clone(),
+        })
+        .await;
+
+    let notices = std::mem::take(&mut *notices.lock().unwrap_or_else(|e| e.into_inner()));
+    (output, notices)
+}
+
+struct NoticeCollector {
+    notices: Arc<Mutex<Vec<ServerNotice>>>,
+}
+
+impl Subscriber for NoticeCollector {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == NOTICE_TARGET
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = MessageVisitor(None);
+        event.record(&mut visitor);
+        if let Some(message) = visitor.0 {
+            self.notices
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(ServerNotice {
+                    is_warning: *event.metadata().level() <= Level::WARN,
+                    message,
+                });
+        }
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+struct MessageVisitor(Option<String>);
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" && self.0.is_none() {
+            self.0 = Some(format!("{:?}", value));
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::models::ConnectionConfig;
+use sqlx::postgres::{PgConnectOptions, PgPool, PgRow, PgSslMode};
+use sqlx::{ConnectOptions, Row};
+
+/// Builds PostgreSQL connect options from a saved connection config
+pub fn pg_connect_options(config: &ConnectionConfig) -> PgConnectOptions {
+    let options = PgConnectOptions::new()
+        .host(&config.host)
+        .port(config.port)
+        .username(&config.username)
+        .password(&config.password)
+        .database(&config.database);
+
+    // Disable statement logging to prevent password leakage
+    let mut options = options.disable_statement_logging();
+
+    // Open in the connection's default schema; PgBouncer rejects the `options`
+    // startup parameter unless told to ignore it, so poolers are left alone
+    if let Some(schema) = config.schema.as_deref().filter(|s| !s.is_empty()) {
+        if matches!(config.pool_mode.as_deref(), None | Some("single")) {
+            let search_path = format!("{},public", quote_identifier(schema));
+            options = options.options([("search_path", escape_startup_option(&search_path))]);
+        }
+    }
+
+    // Transaction and statement pooling hand each transaction to any server
+    // connection, where a cached prepared statement may not exist
+    if uses_transaction_pooling(config) {
+        options.statement_cache_capacity(0)
+    } else {
+        options
+    }
+}
+
+/// Whether session state (SET, prepared statements) can leak between clients
+pub fn uses_transaction_pooling(config: &ConnectionConfig) -> bool {
+    matches!(
+        config.pool_mode.as_deref(),
+        Some("transaction" | "statement")
+    )
+}
+
+/// Formats the DSN a config connects with, masking the password as `***`.
+/// The SSL mode is whatever the connect options resolve to, including `PGSSLMODE`.
+pub fn connection_string_preview(config: &ConnectionConfig) -> String {
+    let ssl_mode = match pg_connect_options(config).get_ssl_mode() {
+        PgSslMode::Disable => "disable",
+        PgSslMode::Allow => "allow",
+        PgSslMode::Prefer => "prefer",
+        PgSslMode::Require => "require",
+        PgSslMode::VerifyCa => "verify-ca",
+        PgSslMode::VerifyFull => "verify-full",
+    };
+
+    // IPv6 literals need brackets to be distinguishable from the port
+    let host = if config.host.contains(':') && !config.host.starts_with('[') {
+        format!("[{}]", config.host)
+    } else {
+        config.host.clone()
+    };
+
+    format!(
+        "postgres://{}:***@{}:{}/{}?sslmode={}",
+        percent_encode(&config.username),
+        host,
+        config.port,
+        percent_encode(&config.database),
+        ssl_mode
+    )
+}
+
+// Startup `-c` options are split on spaces, so spaces and backslashes are escaped
+fn escape_startup_option(value: &str) -> String {
+    value.replace('\\', "\\\\").replace(' ', "\\ ")
+}
+
+// Percent-encodes everything outside the URL unreserved character set
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Opens a short-lived pool for a single command invocation
+pub async fn connect_postgres(config: &ConnectionConfig) -> Result<PgPool, String> {
+    PgPool::connect_with(pg_connect_options(config))
+        .await
+        .map_err(|e| format!("Connection failed: {}", e))
+}
+
+/// Quotes an identifier for safe interpolation into SQL (e.g. `my"table` -> `"my""table"`)
+pub fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// Quotes a string literal for safe interpolation into SQL (e.g. `it's` -> `'it''s'`)
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Quotes a schema-qualified name as `"schema"."name"`
+pub fn qualified_name(schema: &str, name: &str) -> String {
+    format!("{}.{}", quote_identifier(schema), quote_identifier(name))
+}
+
+/// Executes statements in order inside a single transaction, returning total rows affected
+pub async fn execute_batch(pool: &PgPool, statements: &[String]) -> Result<u64, String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    let mut rows_affected = 0;
+    for statement in statements {
+        let result = sqlx::raw_sql(statement)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Error executing statement '{}': {}", statement, e))?;
+        rows_affected += result.rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+    Ok(rows_affected)
+}
+
+/// Converts a result column to JSON; types without a mapping become `null`
+pub fn pg_value_to_json(row: &PgRow, index: usize) -> serde_json::Value {
+    if let Ok(v) = row.try_get::<String, _>(index) {
+        serde_json::json!(v)
+    } else if let Ok(v) = row.try_get::<i32, _>(index) {
+        serde_json::json!(v)
+    } else if let Ok(v) = row.try_get::<i64, _>(index) {
+        serde_json::json!(v)
+    } else if let Ok(v) = row.try_get::<bool, _>(index) {
+        serde_json::json!(v)
+    } else if let Ok(v) = row.try_get::<f64, _>(index) {
+        serde_json::json!(v)
+    } else {
+        serde_json::Value::Null
+    }
+}
@@ -0,0 +1,199 @@
+use crate::models::WarningSeverity;
+use super::sql_script::split_sql_script;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuerySuggestion {
+    pub rule: String, // 'select_star', 'leading_wildcard', 'missing_limit', 'not_in_subquery'
+    pub message: String,
+    pub severity: WarningSeverity,
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String), // keywords and unquoted identifiers, uppercased
+    Literal(String),
+    Symbol(char),
+}
+
+/// Rule-based advice for common query anti-patterns; each rule is reported once.
+pub fn suggest_optimizations(query: &str) -> Vec<QuerySuggestion> {
+    let statements: Vec<Vec<Token>> = split_sql_script(query)
+        .iter()
+        .map(|statement| tokenize(statement))
+        .collect();
+
+    let mut suggestions = Vec::new();
+
+    if statements.iter().any(|tokens| has_select_star(tokens)) {
+        suggestions.push(QuerySuggestion {
+            rule: "select_star".to_string(),
+            message: "SELECT * fetches every column; list only the columns you need".to_string(),
+            severity: WarningSeverity::Low,
+        });
+    }
+
+    if statements.iter().any(|tokens| has_leading_wildcard(tokens)) {
+        suggestions.push(QuerySuggestion {
+            rule: "leading_wildcard".to_string(),
+            message: "LIKE patterns starting with '%' cannot use a btree index".to_string(),
+            severity: WarningSeverity::Medium,
+        });
+    }
+
+    if statements.iter().any(|tokens| is_unbounded_select(tokens)) {
+        suggestions.push(QuerySuggestion {
+            rule: "missing_limit".to_string(),
+            message: "SELECT without LIMIT may return every row of the table".to_string(),
+            severity: WarningSeverity::Low,
+        });
+    }
+
+    if statements.iter().any(|tokens| has_not_in_subquery(tokens)) {
+        suggestions.push(QuerySuggestion {
+            rule: "not_in_subquery".to_string(),
+            message: "NOT IN (SELECT ...) returns no rows if the subquery yields a NULL and \
+                      plans poorly; use NOT EXISTS instead"
+                .to_string(),
+            severity: WarningSeverity::Medium,
+        });
+    }
+
+    suggestions
+}
+
+// SELECT * or SELECT DISTINCT *
+fn has_select_star(tokens: &[Token]) -> bool {
+    tokens.iter().enumerate().any(|(i, token)| {
+        if !is_word(token, "SELECT") {
+            return false;
+        }
+        match tokens.get(i + 1) {
+            Some(next) if is_word(next, "DISTINCT") || is_word(next, "ALL") => {
+                tokens.get(i + 2) == Some(&Token::Symbol('*'))
+            }
+            next => next == Some(&Token::Symbol('*')),
+        }
+    })
+}
+
+fn has_leading_wildcard(tokens: &[Token]) -> bool {
+    tokens.windows(2).any(|pair| {
+        (is_word(&pair[0], "LIKE") || is_word(&pair[0], "ILIKE"))
+            && matches!(&pair[1], Token::Literal(pattern) if pattern.starts_with('%'))
+    })
+}
+
+// A top-level SELECT ... FROM with no LIMIT or FETCH outside parentheses
+fn is_unbounded_select(tokens: &[Token]) -> bool {
+    if !tokens.first().is_some_and(|token| is_word(token, "SELECT")) {
+        return false;
+    }
+
+    let mut depth = 0;
+    let mut has_from = false;
+    for token in tokens {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth -= 1,
+            Token::Word(word) if depth == 0 => match word.as_str() {
+                "FROM" => has_from = true,
+                "LIMIT" | "FETCH" => return false,
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    has_from
+}
+
+fn has_not_in_subquery(tokens: &[Token]) -> bool {
+    tokens.windows(4).any(|window| {
+        is_word(&window[0], "NOT")
+            && is_word(&window[1], "IN")
+            && window[2] == Token::Symbol('(')
+            && is_word(&window[3], "SELECT")
+    })
+}
+
+fn is_word(token: &Token, keyword: &str) -> bool {
+    matches!(token, Token::Word(word) if word == keyword)
+}
+
+// Comments are already stripped by split_sql_script
+fn tokenize(statement: &str) -> Vec<Token> {
+    let chars: Vec<char> = statement.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+            {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            tokens.push(Token::Word(word.to_uppercase()));
+        } else if c == '\'' {
+            // '' is an escaped quote inside the literal
+            let mut literal = String::new();
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == '\'' {
+                    if chars.get(i + 1) == Some(&'\'') {
+                        literal.push('\'');
+                        i += 2;
+                        continue;
+                    }
+                    i += 1;
+                    break;
+                }
+                literal.push(chars[i]);
+                i += 1;
+            }
+            tokens.push(Token::Literal(literal));
+        } else if c == '"' {
+            // Quoted identifiers keep their case and never match a keyword
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += 1;
+            }
+            i += 1;
+            let identifier: String = chars[start..i.min(chars.len())].iter().collect();
+            tokens.push(Token::Word(identifier));
+        } else if c == '$' {
+            let tag_end = chars[i + 1..]
+                .iter()
+                .position(|&ch| !(ch.is_alphanumeric() || ch == '_'))
+                .map(|offset| i + 1 + offset);
+            match tag_end {
+                Some(end) if chars[end] == '$' => {
+                    let tag: String = chars[i..=end].iter().collect();
+                    let body_start = end + 1;
+                    let rest: String = chars[body_start..].iter().collect();
+                    let body_len = rest.find(&tag).unwrap_or(rest.len());
+                    let body: String = rest[..body_len].to_string();
+                    i = body_start + body.chars().count() + tag.chars().count();
+                    tokens.push(Token::Literal(body));
+                }
+                // A positional parameter such as $1
+                _ => {
+                    i += 1;
+                    tokens.push(Token::Symbol('$'));
+                }
+            }
+        } else {
+            tokens.push(Token::Symbol(c));
+            i += 1;
+        }
+    }
+
+    tokens
+}
@@ -0,0 +1,44 @@
+use crate::models::QueryResult;
+
+/// Formats a result as tab-separated text with a header row, ready to paste into a spreadsheet
+pub fn format_result_as_tsv(result: QueryResult) -> String {
+    let mut lines = vec![result.columns.join("\t")];
+    for row in &result.rows {
+        let cells: Vec<String> = row
+            .iter()
+            // Tabs and line breaks inside a value would shift every following cell
+            .map(|value| cell_text(value).replace(['\t', '\n', '\r'], " "))
+            .collect();
+        lines.push(cells.join("\t"));
+    }
+    lines.join("\n")
+}
+
+/// Formats a result as a GitHub-flavored Markdown table
+pub fn format_result_as_markdown_table(result: QueryResult) -> String {
+    let escape = |text: &str| {
+        text.replace('|', "\\|")
+            .replace("\r\n", "<br>")
+            .replace('\n', "<br>")
+    };
+
+    let header: Vec<String> = result.columns.iter().map(|c| escape(c)).collect();
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}|", vec!["---"; header.len()].join("|")),
+    ];
+    for row in &result.rows {
+        let cells: Vec<String> = row.iter().map(|value| escape(&cell_text(value))).collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+    lines.join("\n")
+}
+
+// NULL becomes an empty cell and strings are written without JSON quotes
+fn cell_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -1,8 +1,13 @@
 use crate::models::{
-    EnhancedColumnInfo, EnhancedDatabaseSchema, EnhancedTableInfo, ForeignKeyInfo, IndexInfo,
-    RoutineInfo, ViewInfo,
+    DomainInfo, EnhancedColumnInfo, EnhancedDatabaseSchema, EnhancedTableInfo, EnumTypeInfo,
+    EventTriggerInfo, ForeignKeyInfo, IndexInfo, RlsPolicy, RoutineInfo, StorageParam, ViewInfo,
+    WarningSeverity,
 };
-use crate::constants::{WARNING_TYPE_DATA_LOSS, WARNING_TYPE_BREAKING_CHANGE, SQL_NULLABLE_YES};
+use crate::constants::{
+    MIGRATION_COST_PER_ROW_INDEX, MIGRATION_COST_PER_ROW_REWRITE, MIGRATION_COST_PER_ROW_SCAN,
+    SQL_NULLABLE_YES, WARNING_TYPE_BREAKING_CHANGE, WARNING_TYPE_DATA_LOSS,
+};
+use crate::utils::{qualified_name, quote_identifier, quote_literal, split_sql_script};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
@@ -40,6 +45,14 @@ pub struct ForeignKeyChange {
     pub target_definition: Option<ForeignKeyInfo>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PolicyChange {
+    pub policy_name: String,
+    pub status: DiffStatus,
+    pub source_definition: Option<RlsPolicy>,
+    pub target_definition: Option<RlsPolicy>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TableDifference {
     pub table_name: String,
@@ -47,6 +60,14 @@ pub struct TableDifference {
     pub column_changes: Vec<ColumnChange>,
     pub index_changes: Vec<IndexChange>,
     pub fk_changes: Vec<ForeignKeyChange>,
+    #[serde(default)]
+    pub policy_changes: Vec<PolicyChange>,
+    // Desired row level security state when it differs from the target
+    #[serde(default)]
+    pub rls_enabled_change: Option<bool>,
+    // Storage parameters to create the table with; only set for added tables
+    #[serde(default)]
+    pub storage_options: Vec<StorageParam>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -61,6 +82,7 @@ pub struct ViewChange {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RoutineChange {
     pub routine_name: String,
+    pub signature: String, // name and argument types, which tell overloads apart
     pub status: DiffStatus,
     pub source_definition: Option<RoutineInfo>,
     pub target_definition: Option<RoutineInfo>,
@@ -68,11 +90,31 @@ pub struct RoutineChange {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum WarningSeverity {
-    High,
-    Medium,
-    Low,
+pub struct DomainChange {
+    pub domain_name: String,
+    pub status: DiffStatus,
+    pub source_definition: Option<DomainInfo>,
+    pub target_definition: Option<DomainInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnumChange {
+    pub enum_name: String,
+    pub status: DiffStatus,
+    pub values_added: Vec<String>, // in source order
+    pub values_removed: Vec<String>,
+    #[serde(default)]
+    pub source_definition: Option<EnumTypeInfo>,
+    #[serde(default)]
+    pub target_definition: Option<EnumTypeInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EventTriggerChange {
+    pub trigger_name: String,
+    pub status: DiffStatus,
+    pub source_definition: Option<EventTriggerInfo>,
+    pub target_definition: Option<EventTriggerInfo>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -92,6 +134,10 @@ pub struct ComparisonSummary {
     pub indexes_missing: usize,
     pub views_changed: usize,
     pub routines_changed: usize,
+    #[serde(default)]
+    pub domains_changed: usize,
+    #[serde(default)]
+    pub event_triggers_changed: usize,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -102,20 +148,48 @@ pub struct SchemaComparison {
     pub table_differences: Vec<TableDifference>,
     pub view_differences: Vec<ViewChange>,
     pub routine_differences: Vec<RoutineChange>,
+    #[serde(default)]
+    pub domain_differences: Vec<DomainChange>,
+    #[serde(default)]
+    pub enum_differences: Vec<EnumChange>,
+    #[serde(default)]
+    pub event_trigger_differences: Vec<EventTriggerChange>,
     pub warnings: Vec<ComparisonWarning>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MigrationEstimate {
+    pub estimated_seconds: u64,
+    pub high_risk_operations: Vec<String>,
+    pub notes: Vec<String>,
+}
+
 // Compare two enhanced database schemas
 pub fn compare_schemas(
     source: &EnhancedDatabaseSchema,
     target: &EnhancedDatabaseSchema,
     source_connection: String,
     target_connection: String,
+    target_transaction_pooling: bool,
 ) -> SchemaComparison {
     let table_differences = compare_tables(&source.tables, &target.tables);
     let view_differences = compare_views(&source.views, &target.views);
     let routine_differences = compare_routines(&source.routines, &target.routines);
-    let warnings = generate_warnings(&table_differences, &view_differences, &routine_differences);
+    let domain_differences = compare_domains(&source.domains, &target.domains);
+    let enum_differences = compare_enum_types(&source.enums, &target.enums);
+    let event_trigger_differences =
+        compare_event_triggers(&source.event_triggers, &target.event_triggers);
+    let mut warnings = generate_warnings(
+        &table_differences,
+        &view_differences,
+        &routine_differences,
+        target_transaction_pooling,
+    );
+    warnings.extend(generate_inheritance_warnings(
+        &source.tables,
+        &target.tables,
+        &table_differences,
+    ));
 
     let summary = ComparisonSummary {
         tables_modified: table_differences
@@ -143,6 +217,14 @@ pub fn compare_schemas(
             .iter()
             .filter(|r| matches!(r.status, DiffStatus::Modified | DiffStatus::Added | DiffStatus::Removed))
             .count(),
+        domains_changed: domain_differences
+            .iter()
+            .filter(|d| matches!(d.status, DiffStatus::Modified | DiffStatus::Added | DiffStatus::Removed))
+            .count(),
+        event_triggers_changed: event_trigger_differences
+            .iter()
+            .filter(|t| matches!(t.status, DiffStatus::Modified | DiffStatus::Added | DiffStatus::Removed))
+            .count(),
     };
 
     SchemaComparison {
@@ -152,6 +234,9 @@ pub fn compare_schemas(
         table_differences,
         view_differences,
         routine_differences,
+        domain_differences,
+        enum_differences,
+        event_trigger_differences,
         warnings,
     }
 }
@@ -183,35 +268,66 @@ fn compare_tables(
         let source_table = source_map.get(&table_name);
         let target_table = target_map.get(&table_name);
 
-        let (status, column_changes, index_changes, fk_changes) = match (source_table, target_table) {
-            (Some(src), Some(tgt)) => {
-                // Table exists in both - check for modifications
-                let col_changes = compare_columns(&src.columns, &tgt.columns);
-                let idx_changes = compare_indexes(&src.indexes, &tgt.indexes);
-                let fk_changes = compare_foreign_keys(&src.foreign_keys, &tgt.foreign_keys);
-
-                let is_modified = !col_changes.is_empty() || !idx_changes.is_empty() || !fk_changes.is_empty();
+        let (status, column_changes, index_changes, fk_changes, policy_changes, rls_enabled_change) =
+            match (source_table, target_table) {
+                (Some(src), Some(tgt)) => {
+                    // Table exists in both - check for modifications
+                    let col_changes = compare_columns(&src.columns, &tgt.columns);
+                    let idx_changes = compare_indexes(&src.indexes, &tgt.indexes);
+                    let fk_changes = compare_foreign_keys(&src.foreign_keys, &tgt.foreign_keys);
+                    let policy_changes = compare_policies(&src.rls_policies, &tgt.rls_policies);
+                    let rls_enabled_change =
+                        (src.rls_enabled != tgt.rls_enabled).then_some(src.rls_enabled);
+
+                    // Unchanged columns, indexes and keys are listed too, as Identical
+                    let is_modified = col_changes
+                        .iter()
+                        .any(|c| !matches!(c.status, DiffStatus::Identical))
+                        || idx_changes
+                            .iter()
+                            .any(|i| !matches!(i.status, DiffStatus::Identical))
+                        || fk_changes
+                            .iter()
+                            .any(|f| !matches!(f.status, DiffStatus::Identical))
+                        || policy_changes
+                            .iter()
+                            .any(|p| !matches!(p.status, DiffStatus::Identical))
+                        || rls_enabled_change.is_some();
+
+                    (
+                        if is_modified {
+                            DiffStatus::Modified
+                        } else {
+                            DiffStatus::Identical
+                        },
+                        col_changes,
+                        idx_changes,
+                        fk_changes,
+                        policy_changes,
+                        rls_enabled_change,
+                    )
+                }
+                (Some(src), None) => {
+                    // Table only in source (will be added to target)
+                    (
+                        DiffStatus::Added,
+                        vec![],
+                        vec![],
+                        vec![],
+                        compare_policies(&src.rls_policies, &[]),
+                        src.rls_enabled.then_some(true),
+                    )
+                }
+                (None, Some(_)) => {
+                    // Table only in target (will be removed from target)
+                    (DiffStatus::Removed, vec![], vec![], vec![], vec![], None)
+                }
+                (None, None) => unreachable!(),
+            };
 
-                (
-                    if is_modified {
-                        DiffStatus::Modified
-                    } else {
-                        DiffStatus::Identical
-                    },
-                    col_changes,
-                    idx_changes,
-                    fk_changes,
-                )
-            }
-            (Some(_), None) => {
-                // Table only in source (will be added to target)
-                (DiffStatus::Added, vec![], vec![], vec![])
-            }
-            (None, Some(_)) => {
-                // Table only in target (will be removed from target)
-                (DiffStatus::Removed, vec![], vec![], vec![])
-            }
-            (None, None) => unreachable!(),
+        let storage_options = match (&status, source_table) {
+            (DiffStatus::Added, Some(src)) => src.storage_options.clone(),
+            _ => Vec::new(),
         };
 
         differences.push(TableDifference {
@@ -220,6 +336,9 @@ fn compare_tables(
             column_changes,
             index_changes,
             fk_changes,
+            policy_changes,
+            rls_enabled_change,
+            storage_options,
         });
     }
 
@@ -243,6 +362,11 @@ fn compare_columns(
         .map(|c| (c.column_name.clone(), c))
         .collect();
 
+    // attnum keeps gaps left by dropped columns, and an added or removed column shifts
+    // every later one, so compare each shared column's rank among the shared columns
+    let source_order = column_order(source_cols, &target_map);
+    let target_order = column_order(target_cols, &source_map);
+
     let mut all_columns: HashSet<String> = HashSet::new();
     all_columns.extend(source_map.keys().cloned());
     all_columns.extend(target_map.keys().cloned());
@@ -277,6 +401,25 @@ fn compare_columns(
                         tgt.character_maximum_length, src.character_maximum_length
                     ));
                 }
+                // Only numeric precision can differ for the same type name
+                if src.data_type == tgt.data_type
+                    && (src.numeric_precision, src.numeric_scale)
+                        != (tgt.numeric_precision, tgt.numeric_scale)
+                {
+                    details.push(format!(
+                        "precision: {:?},{:?} → {:?},{:?}",
+                        tgt.numeric_precision,
+                        tgt.numeric_scale,
+                        src.numeric_precision,
+                        src.numeric_scale
+                    ));
+                }
+                if source_order[&col_name] != target_order[&col_name] {
+                    details.push(format!(
+                        "ordinal_position: {} → {}",
+                        target_order[&col_name], source_order[&col_name]
+                    ));
+                }
 
                 let status = if details.is_empty() {
                     DiffStatus::Identical
@@ -303,6 +446,23 @@ fn compare_columns(
     changes
 }
 
+// 1-based position in SELECT * order of each column that the other side also has
+fn column_order(
+    columns: &[EnhancedColumnInfo],
+    other_side: &HashMap<String, &EnhancedColumnInfo>,
+) -> HashMap<String, usize> {
+    let mut ordered: Vec<&EnhancedColumnInfo> = columns
+        .iter()
+        .filter(|c| other_side.contains_key(&c.column_name))
+        .collect();
+    ordered.sort_by_key(|c| c.ordinal_position);
+    ordered
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.column_name.clone(), i + 1))
+        .collect()
+}
+
 // Compare indexes
 fn compare_indexes(source_indexes: &[IndexInfo], target_indexes: &[IndexInfo]) -> Vec<IndexChange> {
     let mut changes = Vec::new();
@@ -405,6 +565,52 @@ fn compare_foreign_keys(
     changes
 }
 
+// Compare row level security policies
+fn compare_policies(source_policies: &[RlsPolicy], target_policies: &[RlsPolicy]) -> Vec<PolicyChange> {
+    let mut changes = Vec::new();
+
+    let source_map: HashMap<String, &RlsPolicy> = source_policies
+        .iter()
+        .map(|p| (p.policy_name.clone(), p))
+        .collect();
+
+    let target_map: HashMap<String, &RlsPolicy> = target_policies
+        .iter()
+        .map(|p| (p.policy_name.clone(), p))
+        .collect();
+
+    let mut all_policies: HashSet<String> = HashSet::new();
+    all_policies.extend(source_map.keys().cloned());
+    all_policies.extend(target_map.keys().cloned());
+
+    for policy_name in all_policies {
+        let source_policy = source_map.get(&policy_name);
+        let target_policy = target_map.get(&policy_name);
+
+        let status = match (source_policy, target_policy) {
+            (Some(src), Some(tgt)) => {
+                if src != tgt {
+                    DiffStatus::Modified
+                } else {
+                    DiffStatus::Identical
+                }
+            }
+            (Some(_), None) => DiffStatus::Added,
+            (None, Some(_)) => DiffStatus::Removed,
+            (None, None) => unreachable!(),
+        };
+
+        changes.push(PolicyChange {
+            policy_name,
+            status,
+            source_definition: source_policy.cloned().cloned(),
+            target_definition: target_policy.cloned().cloned(),
+        });
+    }
+
+    changes
+}
+
 // Compare views
 fn compare_views(source_views: &[ViewInfo], target_views: &[ViewInfo]) -> Vec<ViewChange> {
     let mut changes = Vec::new();
@@ -456,6 +662,12 @@ fn compare_views(source_views: &[ViewInfo], target_views: &[ViewInfo]) -> Vec<Vi
     changes
 }
 
+// Routine reference usable in DROP FUNCTION, e.g. "add(integer, integer)". Overloads
+// share a name, so routines are compared by signature.
+fn routine_signature(routine: &RoutineInfo) -> String {
+    format!("{}({})", routine.routine_name, routine.argument_types.join(", "))
+}
+
 // Compare routines
 fn compare_routines(
     source_routines: &[RoutineInfo],
@@ -465,27 +677,33 @@ fn compare_routines(
 
     let source_map: HashMap<String, &RoutineInfo> = source_routines
         .iter()
-        .map(|r| (r.routine_name.clone(), r))
+        .map(|r| (routine_signature(r), r))
         .collect();
 
     let target_map: HashMap<String, &RoutineInfo> = target_routines
         .iter()
-        .map(|r| (r.routine_name.clone(), r))
+        .map(|r| (routine_signature(r), r))
         .collect();
 
     let mut all_routines: HashSet<String> = HashSet::new();
     all_routines.extend(source_map.keys().cloned());
     all_routines.extend(target_map.keys().cloned());
 
-    for routine_name in all_routines {
-        let source_routine = source_map.get(&routine_name);
-        let target_routine = target_map.get(&routine_name);
+    for signature in all_routines {
+        let source_routine = source_map.get(&signature);
+        let target_routine = target_map.get(&signature);
+        let routine_name = source_routine
+            .or(target_routine)
+            .map(|r| r.routine_name.clone())
+            .unwrap_or_default();
 
         let (status, definition_changed) = match (source_routine, target_routine) {
             (Some(src), Some(tgt)) => {
                 let changed = src.definition != tgt.definition
                     || src.routine_type != tgt.routine_type
-                    || src.return_type != tgt.return_type;
+                    || src.return_type != tgt.return_type
+                    || src.argument_names != tgt.argument_names
+                    || src.volatility != tgt.volatility;
                 (
                     if changed {
                         DiffStatus::Modified
@@ -502,6 +720,7 @@ fn compare_routines(
 
         changes.push(RoutineChange {
             routine_name,
+            signature,
             status,
             source_definition: source_routine.cloned().cloned(),
             target_definition: target_routine.cloned().cloned(),
@@ -512,14 +731,232 @@ fn compare_routines(
     changes
 }
 
+// Compare domain types
+fn compare_domains(
+    source_domains: &[DomainInfo],
+    target_domains: &[DomainInfo],
+) -> Vec<DomainChange> {
+    let mut changes = Vec::new();
+
+    let source_map: HashMap<String, &DomainInfo> = source_domains
+        .iter()
+        .map(|d| (d.domain_name.clone(), d))
+        .collect();
+
+    let target_map: HashMap<String, &DomainInfo> = target_domains
+        .iter()
+        .map(|d| (d.domain_name.clone(), d))
+        .collect();
+
+    let mut all_domains: HashSet<String> = HashSet::new();
+    all_domains.extend(source_map.keys().cloned());
+    all_domains.extend(target_map.keys().cloned());
+
+    for domain_name in all_domains {
+        let source_domain = source_map.get(&domain_name);
+        let target_domain = target_map.get(&domain_name);
+
+        let status = match (source_domain, target_domain) {
+            (Some(src), Some(tgt)) => {
+                if src != tgt {
+                    DiffStatus::Modified
+                } else {
+                    DiffStatus::Identical
+                }
+            }
+            (Some(_), None) => DiffStatus::Added,
+            (None, Some(_)) => DiffStatus::Removed,
+            (None, None) => unreachable!(),
+        };
+
+        changes.push(DomainChange {
+            domain_name,
+            status,
+            source_definition: source_domain.cloned().cloned(),
+            target_definition: target_domain.cloned().cloned(),
+        });
+    }
+
+    changes
+}
+
+// Compare enum types by their ordered value lists
+fn compare_enum_types(
+    source_enums: &[EnumTypeInfo],
+    target_enums: &[EnumTypeInfo],
+) -> Vec<EnumChange> {
+    let mut changes = Vec::new();
+
+    let source_map: HashMap<String, &EnumTypeInfo> = source_enums
+        .iter()
+        .map(|e| (e.enum_name.clone(), e))
+        .collect();
+
+    let target_map: HashMap<String, &EnumTypeInfo> = target_enums
+        .iter()
+        .map(|e| (e.enum_name.clone(), e))
+        .collect();
+
+    let mut all_enums: HashSet<String> = HashSet::new();
+    all_enums.extend(source_map.keys().cloned());
+    all_enums.extend(target_map.keys().cloned());
+
+    for enum_name in all_enums {
+        let source_enum = source_map.get(&enum_name);
+        let target_enum = target_map.get(&enum_name);
+        let source_values = source_enum.map(|e| e.values.as_slice()).unwrap_or_default();
+        let target_values = target_enum.map(|e| e.values.as_slice()).unwrap_or_default();
+
+        let status = match (source_enum, target_enum) {
+            (Some(_), Some(_)) => {
+                // Reordering alone also counts, since enum values compare by position
+                if source_values != target_values {
+                    DiffStatus::Modified
+                } else {
+                    DiffStatus::Identical
+                }
+            }
+            (Some(_), None) => DiffStatus::Added,
+            (None, Some(_)) => DiffStatus::Removed,
+            (None, None) => unreachable!(),
+        };
+
+        let values_added = source_values
+            .iter()
+            .filter(|v| !target_values.contains(v))
+            .cloned()
+            .collect();
+        let values_removed = target_values
+            .iter()
+            .filter(|v| !source_values.contains(v))
+            .cloned()
+            .collect();
+
+        changes.push(EnumChange {
+            enum_name,
+            status,
+            values_added,
+            values_removed,
+            source_definition: source_enum.cloned().cloned(),
+            target_definition: target_enum.cloned().cloned(),
+        });
+    }
+
+    changes
+}
+
+// Compare event triggers
+fn compare_event_triggers(
+    source_triggers: &[EventTriggerInfo],
+    target_triggers: &[EventTriggerInfo],
+) -> Vec<EventTriggerChange> {
+    let mut changes = Vec::new();
+
+    let source_map: HashMap<String, &EventTriggerInfo> = source_triggers
+        .iter()
+        .map(|t| (t.trigger_name.clone(), t))
+        .collect();
+
+    let target_map: HashMap<String, &EventTriggerInfo> = target_triggers
+        .iter()
+        .map(|t| (t.trigger_name.clone(), t))
+        .collect();
+
+    let mut all_triggers: HashSet<String> = HashSet::new();
+    all_triggers.extend(source_map.keys().cloned());
+    all_triggers.extend(target_map.keys().cloned());
+
+    for trigger_name in all_triggers {
+        let source_trigger = source_map.get(&trigger_name);
+        let target_trigger = target_map.get(&trigger_name);
+
+        let status = match (source_trigger, target_trigger) {
+            (Some(src), Some(tgt)) => {
+                if src != tgt {
+                    DiffStatus::Modified
+                } else {
+                    DiffStatus::Identical
+                }
+            }
+            (Some(_), None) => DiffStatus::Added,
+            (None, Some(_)) => DiffStatus::Removed,
+            (None, None) => unreachable!(),
+        };
+
+        changes.push(EventTriggerChange {
+            trigger_name,
+            status,
+            source_definition: source_trigger.cloned().cloned(),
+            target_definition: target_trigger.cloned().cloned(),
+        });
+    }
+
+    changes
+}
+
 // Generate warnings based on detected changes
 fn generate_warnings(
     table_diffs: &[TableDifference],
     _view_diffs: &[ViewChange],
-    _routine_diffs: &[RoutineChange],
+    routine_diffs: &[RoutineChange],
+    target_transaction_pooling: bool,
 ) -> Vec<ComparisonWarning> {
     let mut warnings = Vec::new();
 
+    // Indexes and generated columns only accept IMMUTABLE functions
+    for routine_change in routine_diffs {
+        let (Some(source), Some(target)) = (
+            &routine_change.source_definition,
+            &routine_change.target_definition,
+        ) else {
+            continue;
+        };
+        if target.volatility.as_deref() == Some("IMMUTABLE")
+            && source.volatility.as_deref() == Some("VOLATILE")
+        {
+            warnings.push(ComparisonWarning {
+                severity: WarningSeverity::Medium,
+                warning_type: WARNING_TYPE_BREAKING_CHANGE.to_string(),
+                message: format!(
+                    "Routine '{}' changes from IMMUTABLE to VOLATILE",
+                    routine_change.routine_name
+                ),
+                affected_object: routine_change.signature.clone(),
+                details: Some(
+                    "Indexes, generated columns and constant folding that rely on it will break"
+                        .to_string(),
+                ),
+            });
+        }
+    }
+
+    // Behind a transaction/statement pooler, a session-level SET leaks into
+    // whichever client gets the server connection next
+    if target_transaction_pooling {
+        for routine_change in routine_diffs {
+            if !matches!(routine_change.status, DiffStatus::Added | DiffStatus::Modified) {
+                continue;
+            }
+            let Some(routine) = &routine_change.source_definition else {
+                continue;
+            };
+            if contains_set_command(routine.definition.as_deref().unwrap_or_default()) {
+                warnings.push(ComparisonWarning {
+                    severity: WarningSeverity::Medium,
+                    warning_type: WARNING_TYPE_BREAKING_CHANGE.to_string(),
+                    message: format!(
+                        "Routine '{}' runs SET commands, but the target uses transaction pooling",
+                        routine_change.routine_name
+                    ),
+                    affected_object: routine_change.signature.clone(),
+                    details: Some(
+                        "Use SET LOCAL so the setting ends with the transaction".to_string(),
+                    ),
+                });
+            }
+        }
+    }
+
     for table_diff in table_diffs {
         // Warn about dropped tables
         if matches!(table_diff.status, DiffStatus::Removed) {
@@ -547,6 +984,24 @@ fn generate_warnings(
                 });
             }
 
+            // PostgreSQL cannot reorder columns in place, so the script leaves these alone
+            if col_change
+                .changes
+                .iter()
+                .any(|c| c.starts_with("ordinal_position:"))
+            {
+                warnings.push(ComparisonWarning {
+                    severity: WarningSeverity::Low,
+                    warning_type: WARNING_TYPE_BREAKING_CHANGE.to_string(),
+                    message: format!(
+                        "Column '{}.{}' is at a different position",
+                        table_diff.table_name, col_change.column_name
+                    ),
+                    affected_object: format!("{}.{}", table_diff.table_name, col_change.column_name),
+                    details: Some("SELECT * returns the columns in a different order".to_string()),
+                });
+            }
+
             // Warn about type changes
             if matches!(col_change.status, DiffStatus::Modified) {
                 if col_change.changes.iter().any(|c| c.starts_with("type:")) {
@@ -568,6 +1023,205 @@ fn generate_warnings(
     warnings
 }
 
+// Session-level SET statements inside a routine body. SET has to start a statement,
+// possibly within a PL/pgSQL block, so UPDATE ... SET clauses do not count; neither
+// do SET LOCAL, SET TRANSACTION and SET CONSTRAINTS, which end with the transaction.
+fn contains_set_command(definition: &str) -> bool {
+    split_sql_script(definition).iter().any(|statement| {
+        let words: Vec<String> = statement
+            .split_whitespace()
+            .map(|word| word.to_ascii_uppercase())
+            .collect();
+        words.iter().enumerate().any(|(i, word)| {
+            word == "SET"
+                && (i == 0 || matches!(words[i - 1].as_str(), "BEGIN" | "THEN" | "ELSE" | "LOOP"))
+                && !matches!(
+                    words.get(i + 1).map(String::as_str),
+                    Some("LOCAL" | "TRANSACTION" | "CONSTRAINTS")
+                )
+        })
+    })
+}
+
+// Generate warnings for tables that take part in inheritance hierarchies
+fn generate_inheritance_warnings(
+    source_tables: &[EnhancedTableInfo],
+    target_tables: &[EnhancedTableInfo],
+    table_diffs: &[TableDifference],
+) -> Vec<ComparisonWarning> {
+    let mut warnings = Vec::new();
+
+    let source_map: HashMap<&str, &EnhancedTableInfo> = source_tables
+        .iter()
+        .map(|t| (t.table_name.as_str(), t))
+        .collect();
+    let target_map: HashMap<&str, &EnhancedTableInfo> = target_tables
+        .iter()
+        .map(|t| (t.table_name.as_str(), t))
+        .collect();
+
+    for table_diff in table_diffs {
+        let name = table_diff.table_name.as_str();
+        let source_table = source_map.get(name);
+        let target_table = target_map.get(name);
+
+        // The migration script doesn't emit INHERIT / NO INHERIT
+        if let (Some(src), Some(tgt)) = (source_table, target_table) {
+            if src.parent_table != tgt.parent_table {
+                warnings.push(ComparisonWarning {
+                    severity: WarningSeverity::Medium,
+                    warning_type: WARNING_TYPE_BREAKING_CHANGE.to_string(),
+                    message: format!(
+                        "Parent table of '{}' differs: {} → {}",
+                        name,
+                        tgt.parent_table.as_deref().unwrap_or("none"),
+                        src.parent_table.as_deref().unwrap_or("none")
+                    ),
+                    affected_object: name.to_string(),
+                    details: Some(
+                        "Apply ALTER TABLE ... INHERIT / NO INHERIT manually".to_string(),
+                    ),
+                });
+            }
+        }
+
+        let Some(tgt) = target_table else { continue };
+        if tgt.child_tables.is_empty() {
+            continue;
+        }
+        let children = tgt.child_tables.join(", ");
+
+        if matches!(table_diff.status, DiffStatus::Removed) {
+            warnings.push(ComparisonWarning {
+                severity: WarningSeverity::High,
+                warning_type: WARNING_TYPE_DATA_LOSS.to_string(),
+                message: format!(
+                    "Dropping parent table '{}' with CASCADE also drops inheriting tables: {}",
+                    name, children
+                ),
+                affected_object: name.to_string(),
+                details: Some("Rows in child tables will be lost as well".to_string()),
+            });
+        } else if table_diff
+            .column_changes
+            .iter()
+            .any(|c| !matches!(c.status, DiffStatus::Identical))
+        {
+            warnings.push(ComparisonWarning {
+                severity: WarningSeverity::Medium,
+                warning_type: WARNING_TYPE_BREAKING_CHANGE.to_string(),
+                message: format!(
+                    "Column changes on '{}' propagate to inheriting tables: {}",
+                    name, children
+                ),
+                affected_object: name.to_string(),
+                details: None,
+            });
+        }
+    }
+
+    warnings
+}
+
+/// Tables whose row counts are needed to estimate the migration duration
+pub fn tables_needing_row_counts(comparison: &SchemaComparison) -> Vec<String> {
+    comparison
+        .table_differences
+        .iter()
+        .filter(|t| matches!(t.status, DiffStatus::Modified))
+        .filter(|t| {
+            t.column_changes.iter().any(|c| {
+                c.changes
+                    .iter()
+                    .any(|d| d.starts_with("type:") || d.starts_with("nullable:"))
+            }) || t
+                .index_changes
+                .iter()
+                .any(|i| matches!(i.status, DiffStatus::Added | DiffStatus::Modified))
+        })
+        .map(|t| t.table_name.clone())
+        .collect()
+}
+
+/// Estimate how long a migration will take from per-table row counts.
+/// These are deliberately conservative, rough guesses rather than measurements.
+pub fn estimate_migration(
+    comparison: &SchemaComparison,
+    row_counts: &HashMap<String, i64>,
+) -> MigrationEstimate {
+    let mut seconds = 0.0;
+    let mut high_risk_operations = Vec::new();
+    let mut notes = vec![
+        "Rough estimate only: actual duration depends on hardware, locks, and concurrent load"
+            .to_string(),
+    ];
+
+    for table_diff in &comparison.table_differences {
+        if !matches!(table_diff.status, DiffStatus::Modified) {
+            continue;
+        }
+
+        let rows = row_counts
+            .get(&table_diff.table_name)
+            .copied()
+            .unwrap_or(0)
+            .max(0) as f64;
+
+        for col_change in &table_diff.column_changes {
+            let object = format!("{}.{}", table_diff.table_name, col_change.column_name);
+
+            // Type changes rewrite the whole table under an ACCESS EXCLUSIVE lock
+            if col_change.changes.iter().any(|c| c.starts_with("type:")) {
+                seconds += rows * MIGRATION_COST_PER_ROW_REWRITE;
+                high_risk_operations.push(format!(
+                    "ALTER COLUMN TYPE on {} rewrites ~{} rows",
+                    object, rows as i64
+                ));
+            }
+
+            // SET NOT NULL scans every row to validate existing data
+            let adds_not_null = col_change.changes.iter().any(|c| c.starts_with("nullable:"))
+                && col_change
+                    .source_definition
+                    .as_ref()
+                    .is_some_and(|c| c.is_nullable != SQL_NULLABLE_YES);
+            if adds_not_null {
+                seconds += rows * MIGRATION_COST_PER_ROW_SCAN;
+                high_risk_operations.push(format!(
+                    "SET NOT NULL on {} requires a full table scan of ~{} rows",
+                    object, rows as i64
+                ));
+            }
+        }
+
+        let indexes_built = table_diff
+            .index_changes
+            .iter()
+            .filter(|i| matches!(i.status, DiffStatus::Added | DiffStatus::Modified))
+            .count();
+        if indexes_built > 0 {
+            seconds += rows * MIGRATION_COST_PER_ROW_INDEX * indexes_built as f64;
+            notes.push(format!(
+                "Building {} index(es) on {} blocks writes unless created CONCURRENTLY",
+                indexes_built, table_diff.table_name
+            ));
+        }
+
+        if !row_counts.contains_key(&table_diff.table_name) {
+            notes.push(format!(
+                "Row count unavailable for {}; its operations were not costed",
+                table_diff.table_name
+            ));
+        }
+    }
+
+    MigrationEstimate {
+        estimated_seconds: seconds.ceil() as u64,
+        high_risk_operations,
+        notes,
+    }
+}
+
 /// Generate PostgreSQL migration script from schema comparison
 pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
     let mut script = String::new();
@@ -591,6 +1245,124 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
 
     let mut has_changes = false;
 
+    // Enum types come first since domains and table columns may use them
+    let enum_changes: Vec<_> = comparison
+        .enum_differences
+        .iter()
+        .filter(|e| !matches!(e.status, DiffStatus::Identical))
+        .collect();
+
+    if !enum_changes.is_empty() {
+        has_changes = true;
+        script.push_str(
+            "-- ============================================\n\
+             -- ENUM TYPES\n\
+             -- ============================================\n\n",
+        );
+
+        for enum_change in enum_changes {
+            let source_values = enum_change
+                .source_definition
+                .as_ref()
+                .map(|e| e.values.as_slice())
+                .unwrap_or_default();
+            let target_values = enum_change
+                .target_definition
+                .as_ref()
+                .map(|e| e.values.as_slice())
+                .unwrap_or_default();
+
+            match enum_change.status {
+                DiffStatus::Added => {
+                    script.push_str(&create_enum_sql(&enum_change.enum_name, source_values));
+                    script.push('\n');
+                }
+                DiffStatus::Modified if is_enum_append_only(source_values, target_values) => {
+                    script.push_str(
+                        "-- NOTE: ADD VALUE statements are committed on their own before\n\
+                         -- the rest of the migration runs\n",
+                    );
+                    for value in &enum_change.values_added {
+                        script.push_str(&format!(
+                            "ALTER TYPE {} ADD VALUE IF NOT EXISTS {}{};\n",
+                            enum_change.enum_name,
+                            quote_literal(value),
+                            enum_value_position(source_values, target_values, value)
+                        ));
+                    }
+                    script.push('\n');
+                }
+                DiffStatus::Modified => {
+                    // Values cannot be dropped or reordered in place
+                    let old_name = format!("{}_old", enum_change.enum_name);
+                    script.push_str(
+                        "-- WARNING: Removing or reordering enum values recreates the type\n",
+                    );
+                    if !enum_change.values_removed.is_empty() {
+                        script.push_str(&format!(
+                            "-- Rows using removed values ({}) must be updated first\n",
+                            enum_change.values_removed.join(", ")
+                        ));
+                    }
+                    script.push_str(&format!(
+                        "ALTER TYPE {} RENAME TO {};\n",
+                        enum_change.enum_name, old_name
+                    ));
+                    script.push_str(&create_enum_sql(&enum_change.enum_name, source_values));
+                    if let Some(target_enum) = &enum_change.target_definition {
+                        push_enum_column_conversions(&mut script, target_enum);
+                    }
+                    script.push_str(&format!("DROP TYPE IF EXISTS {};\n\n", old_name));
+                }
+                // Removed types are dropped at the end, once nothing uses them
+                _ => {}
+            }
+        }
+    }
+
+    // Domains come first since table columns may depend on them
+    let domain_changes: Vec<_> = comparison
+        .domain_differences
+        .iter()
+        .filter(|d| !matches!(d.status, DiffStatus::Identical))
+        .collect();
+
+    if !domain_changes.is_empty() {
+        has_changes = true;
+        script.push_str(
+            "-- ============================================\n\
+             -- DOMAINS\n\
+             -- ============================================\n\n",
+        );
+
+        for domain_change in domain_changes {
+            match domain_change.status {
+                DiffStatus::Added => {
+                    if let Some(domain) = &domain_change.source_definition {
+                        script.push_str(&create_domain_sql(domain));
+                    }
+                }
+                DiffStatus::Modified => {
+                    script.push_str(&format!(
+                        "-- WARNING: Recreating a domain fails while columns still use it\n\
+                         DROP DOMAIN IF EXISTS {};\n",
+                        domain_change.domain_name
+                    ));
+                    if let Some(domain) = &domain_change.source_definition {
+                        script.push_str(&create_domain_sql(domain));
+                    }
+                }
+                DiffStatus::Removed => {
+                    script.push_str(&format!(
+                        "DROP DOMAIN IF EXISTS {};\n\n",
+                        domain_change.domain_name
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
     // Table modifications
     let modified_tables: Vec<_> = comparison
         .table_differences
@@ -760,6 +1532,9 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
                 }
             }
 
+            // Row level security changes
+            push_rls_changes(&mut script, table_diff);
+
             script.push('\n');
         }
     }
@@ -814,7 +1589,10 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
                 .collect();
 
             script.push_str(&columns.join(",\n"));
-            script.push_str("\n);\n\n");
+            script.push_str(&format!(
+                "\n){};\n\n",
+                storage_options_sql(&table_diff.storage_options)
+            ));
 
             // Indexes for new table
             for idx_change in &table_diff.index_changes {
@@ -837,6 +1615,9 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
                 }
             }
 
+            // Row level security for new table
+            push_rls_changes(&mut script, table_diff);
+
             script.push('\n');
         }
     }
@@ -916,7 +1697,7 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
                 DiffStatus::Added | DiffStatus::Modified => {
                     script.push_str(&format!(
                         "DROP FUNCTION IF EXISTS {} CASCADE;\n\n",
-                        routine_change.routine_name
+                        routine_change.signature
                     ));
                     if let Some(routine_info) = &routine_change.target_definition {
                         if let Some(def) = &routine_info.definition {
@@ -927,7 +1708,80 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
                 DiffStatus::Removed => {
                     script.push_str(&format!(
                         "DROP FUNCTION IF EXISTS {} CASCADE;\n\n",
-                        routine_change.routine_name
+                        routine_change.signature
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Removed enum types go after the tables, columns, views and functions using them
+    let removed_enums: Vec<_> = comparison
+        .enum_differences
+        .iter()
+        .filter(|e| matches!(e.status, DiffStatus::Removed))
+        .collect();
+
+    if !removed_enums.is_empty() {
+        has_changes = true;
+        script.push_str(
+            "-- ============================================\n\
+             -- DROPPED ENUM TYPES\n\
+             -- ============================================\n\n",
+        );
+
+        for enum_change in removed_enums {
+            script.push_str(&format!(
+                "DROP TYPE IF EXISTS {};\n\n",
+                enum_change.enum_name
+            ));
+        }
+    }
+
+    // Event triggers go last since they reference functions
+    let event_trigger_changes: Vec<_> = comparison
+        .event_trigger_differences
+        .iter()
+        .filter(|t| !matches!(t.status, DiffStatus::Identical))
+        .collect();
+
+    if !event_trigger_changes.is_empty() {
+        has_changes = true;
+        script.push_str(
+            "-- ============================================\n\
+             -- EVENT TRIGGERS\n\
+             -- ============================================\n\n",
+        );
+
+        for trigger_change in event_trigger_changes {
+            match (
+                &trigger_change.status,
+                &trigger_change.source_definition,
+                &trigger_change.target_definition,
+            ) {
+                (DiffStatus::Added, Some(src), _) => {
+                    script.push_str(&create_event_trigger_sql(src));
+                }
+                (DiffStatus::Modified, Some(src), Some(tgt)) => {
+                    if src.event == tgt.event
+                        && src.function_name == tgt.function_name
+                        && src.tags == tgt.tags
+                    {
+                        // Only the enabled state differs
+                        script.push_str(&alter_event_trigger_sql(src));
+                    } else {
+                        script.push_str(&format!(
+                            "DROP EVENT TRIGGER IF EXISTS {};\n",
+                            trigger_change.trigger_name
+                        ));
+                        script.push_str(&create_event_trigger_sql(src));
+                    }
+                }
+                (DiffStatus::Removed, _, _) => {
+                    script.push_str(&format!(
+                        "DROP EVENT TRIGGER IF EXISTS {};\n\n",
+                        trigger_change.trigger_name
                     ));
                 }
                 _ => {}
@@ -946,7 +1800,9 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
             + comparison.summary.tables_removed
             + comparison.summary.tables_modified
             + comparison.summary.views_changed
-            + comparison.summary.routines_changed;
+            + comparison.summary.routines_changed
+            + comparison.summary.domains_changed
+            + comparison.summary.event_triggers_changed;
 
         script.push_str(&format!("-- Total affected objects: {}\n", total_changes));
 
@@ -959,3 +1815,606 @@ pub fn generate_migration_script(comparison: &SchemaComparison) -> String {
 
     script
 }
+
+fn create_enum_sql(enum_name: &str, values: &[String]) -> String {
+    let values: Vec<String> = values.iter().map(|v| quote_literal(v)).collect();
+    format!(
+        "CREATE TYPE {} AS ENUM ({});\n",
+        enum_name,
+        values.join(", ")
+    )
+}
+
+// True when the target keeps all of its values in the source's order, so the new
+// values can be added in place with ALTER TYPE ... ADD VALUE
+fn is_enum_append_only(source_values: &[String], target_values: &[String]) -> bool {
+    let kept: Vec<&String> = source_values
+        .iter()
+        .filter(|v| target_values.contains(v))
+        .collect();
+    kept.len() == target_values.len() && kept.iter().zip(target_values).all(|(a, b)| *a == b)
+}
+
+// BEFORE/AFTER clause placing an added value where it sits in the source. Values are
+// added in source order, so the preceding source value always exists by then.
+fn enum_value_position(source_values: &[String], target_values: &[String], value: &str) -> String {
+    match source_values.iter().position(|v| v == value) {
+        Some(index) if index > 0 => format!(" AFTER {}", quote_literal(&source_values[index - 1])),
+        _ => target_values
+            .first()
+            .map(|first| format!(" BEFORE {}", quote_literal(first)))
+            .unwrap_or_default(),
+    }
+}
+
+// Move every column of a renamed enum over to the recreated type. Defaults are
+// dropped first since they cannot be cast automatically, then restored.
+fn push_enum_column_conversions(script: &mut String, target_enum: &EnumTypeInfo) {
+    for column in &target_enum.columns {
+        let table = qualified_name(&column.schema_name, &column.table_name);
+        let column_name = quote_identifier(&column.column_name);
+        let (new_type, cast) = if column.is_array {
+            (format!("{}[]", target_enum.enum_name), "text[]")
+        } else {
+            (target_enum.enum_name.clone(), "text")
+        };
+
+        if column.column_default.is_some() {
+            script.push_str(&format!(
+                "ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;\n",
+                table, column_name
+            ));
+        }
+        script.push_str(&format!(
+            "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{}::{};\n",
+            table, column_name, new_type, column_name, cast, new_type
+        ));
+        if let Some(default) = &column.column_default {
+            script.push_str(&format!(
+                "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {};\n",
+                table, column_name, default
+            ));
+        }
+    }
+}
+
+// Render a WITH (...) clause; non-numeric, non-keyword values are quoted
+fn storage_options_sql(options: &[StorageParam]) -> String {
+    if options.is_empty() {
+        return String::new();
+    }
+
+    let params: Vec<String> = options
+        .iter()
+        .map(|param| {
+            let is_plain = param
+                .value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+            if is_plain {
+                format!("{}={}", param.name, param.value)
+            } else {
+                format!("{}='{}'", param.name, param.value.replace('\'', "''"))
+            }
+        })
+        .collect();
+
+    format!(" WITH ({})", params.join(", "))
+}
+
+// Append ENABLE/DISABLE ROW LEVEL SECURITY and policy DDL for a table difference
+fn push_rls_changes(script: &mut String, table_diff: &TableDifference) {
+    if let Some(enabled) = table_diff.rls_enabled_change {
+        script.push_str(&format!(
+            "ALTER TABLE {} {} ROW LEVEL SECURITY;\n",
+            table_diff.table_name,
+            if enabled { "ENABLE" } else { "DISABLE" }
+        ));
+    }
+
+    for policy_change in &table_diff.policy_changes {
+        match policy_change.status {
+            DiffStatus::Added => {
+                if let Some(policy) = &policy_change.source_definition {
+                    script.push_str(&create_policy_sql(policy));
+                }
+            }
+            DiffStatus::Removed => {
+                script.push_str(&format!(
+                    "DROP POLICY IF EXISTS {} ON {};\n",
+                    policy_change.policy_name, table_diff.table_name
+                ));
+            }
+            DiffStatus::Modified => {
+                // Drop and recreate
+                script.push_str(&format!(
+                    "DROP POLICY IF EXISTS {} ON {};\n",
+                    policy_change.policy_name, table_diff.table_name
+                ));
+                if let Some(policy) = &policy_change.source_definition {
+                    script.push_str(&create_policy_sql(policy));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Build a CREATE POLICY statement
+fn create_policy_sql(policy: &RlsPolicy) -> String {
+    let mut sql = format!(
+        "CREATE POLICY {} ON {}",
+        policy.policy_name, policy.table_name
+    );
+    if policy.restrictive {
+        sql.push_str(" AS RESTRICTIVE");
+    }
+    sql.push_str(&format!(" FOR {}", policy.command));
+    if !policy.roles.is_empty() {
+        sql.push_str(&format!(" TO {}", policy.roles.join(", ")));
+    }
+    if let Some(using_expr) = &policy.using_expr {
+        sql.push_str(&format!(" USING ({})", using_expr));
+    }
+    if let Some(check_expr) = &policy.check_expr {
+        sql.push_str(&format!(" WITH CHECK ({})", check_expr));
+    }
+    sql.push_str(";\n");
+    sql
+}
+
+// Build a CREATE DOMAIN statement
+fn create_domain_sql(domain: &DomainInfo) -> String {
+    let mut sql = format!("CREATE DOMAIN {} AS {}", domain.domain_name, domain.base_type);
+    if let Some(default) = &domain.default {
+        sql.push_str(&format!(" DEFAULT {}", default));
+    }
+    if domain.not_null {
+        sql.push_str(" NOT NULL");
+    }
+    for constraint in &domain.constraints {
+        sql.push_str(&format!(" {}", constraint));
+    }
+    sql.push_str(";\n\n");
+    sql
+}
+
+// Build a CREATE EVENT TRIGGER statement, followed by ALTER when not enabled by default
+fn create_event_trigger_sql(trigger: &EventTriggerInfo) -> String {
+    let mut sql = format!(
+        "CREATE EVENT TRIGGER {} ON {}\n",
+        trigger.trigger_name, trigger.event
+    );
+    if !trigger.tags.is_empty() {
+        let tags: Vec<String> = trigger.tags.iter().map(|t| quote_literal(t)).collect();
+        sql.push_str(&format!("    WHEN TAG IN ({})\n", tags.join(", ")));
+    }
+    sql.push_str(&format!("    EXECUTE FUNCTION {}();\n", trigger.function_name));
+    if trigger.enabled != "ORIGIN" {
+        sql.push_str(&alter_event_trigger_sql(trigger));
+    } else {
+        sql.push('\n');
+    }
+    sql
+}
+
+// Build an ALTER EVENT TRIGGER statement matching the trigger's enabled state
+fn alter_event_trigger_sql(trigger: &EventTriggerInfo) -> String {
+    let action = match trigger.enabled.as_str() {
+        "DISABLED" => "DISABLE",
+        "REPLICA" => "ENABLE REPLICA",
+        "ALWAYS" => "ENABLE ALWAYS",
+        _ => "ENABLE",
+    };
+    format!("ALTER EVENT TRIGGER {} {};\n\n", trigger.trigger_name, action)
+}
+
+const HTML_REPORT_STYLE: &str = "
+    body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif; margin: 2rem; color: #1f2328; }
+    h1 { font-size: 1.5rem; margin-bottom: 0.25rem; }
+    h2 { font-size: 1.2rem; margin-top: 2rem; border-bottom: 1px solid #d0d7de; padding-bottom: 0.25rem; }
+    h3 { font-size: 1rem; margin-top: 1.5rem; }
+    .meta { color: #656d76; margin-top: 0; }
+    table { border-collapse: collapse; width: 100%; margin: 0.5rem 0 1rem; font-size: 0.9rem; }
+    th, td { border: 1px solid #d0d7de; padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }
+    th { background: #f6f8fa; }
+    code { font-family: ui-monospace, SFMono-Regular, Menlo, monospace; font-size: 0.85rem; }
+    .status { font-weight: 600; text-transform: uppercase; font-size: 0.75rem; }
+    .added { color: #1a7f37; }
+    .removed { color: #cf222e; }
+    .modified { color: #9a6700; }
+    .high { color: #cf222e; font-weight: 600; }
+    .medium { color: #9a6700; font-weight: 600; }
+    .low { color: #656d76; font-weight: 600; }
+";
+
+/// Render a comparison as a self-contained HTML page for sharing with reviewers.
+/// All styling is inline so the file opens anywhere without external assets.
+pub fn generate_html_report(comparison: &SchemaComparison) -> Result<String, String> {
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    let summary = &comparison.summary;
+    let mut html = String::new();
+
+    html.push_str(&format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Schema comparison: {} vs {}</title>\n<style>{}</style>\n</head>\n<body>\n\
+         <h1>Schema comparison</h1>\n\
+         <p class=\"meta\">Source: <strong>{}</strong> &middot; Target: <strong>{}</strong> &middot; Generated {}</p>\n",
+        escape_html(&comparison.source_connection),
+        escape_html(&comparison.target_connection),
+        HTML_REPORT_STYLE,
+        escape_html(&comparison.source_connection),
+        escape_html(&comparison.target_connection),
+        timestamp
+    ));
+
+    // Summary
+    html.push_str("<h2>Summary</h2>\n<table>\n");
+    for (label, count) in [
+        ("Tables added", summary.tables_added),
+        ("Tables removed", summary.tables_removed),
+        ("Tables modified", summary.tables_modified),
+        ("Indexes missing", summary.indexes_missing),
+        ("Views changed", summary.views_changed),
+        ("Routines changed", summary.routines_changed),
+        ("Domains changed", summary.domains_changed),
+        ("Event triggers changed", summary.event_triggers_changed),
+        ("Warnings", comparison.warnings.len()),
+    ] {
+        html.push_str(&format!("<tr><th>{}</th><td>{}</td></tr>\n", label, count));
+    }
+    html.push_str("</table>\n");
+
+    // Warnings
+    if !comparison.warnings.is_empty() {
+        html.push_str(
+            "<h2>Warnings</h2>\n<table>\n\
+             <tr><th>Severity</th><th>Type</th><th>Object</th><th>Message</th></tr>\n",
+        );
+        for warning in &comparison.warnings {
+            let severity = match warning.severity {
+                WarningSeverity::High => "high",
+                WarningSeverity::Medium => "medium",
+                WarningSeverity::Low => "low",
+            };
+            let details = warning
+                .details
+                .as_ref()
+                .map(|d| format!("<br><small>{}</small>", escape_html(d)))
+                .unwrap_or_default();
+            html.push_str(&format!(
+                "<tr><td class=\"{}\">{}</td><td>{}</td><td><code>{}</code></td><td>{}{}</td></tr>\n",
+                severity,
+                severity,
+                escape_html(&warning.warning_type),
+                escape_html(&warning.affected_object),
+                escape_html(&warning.message),
+                details
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    // Added and removed tables; the comparison keeps no column details for these
+    for (title, wanted) in [("Added tables", "added"), ("Removed tables", "removed")] {
+        let tables: Vec<_> = comparison
+            .table_differences
+            .iter()
+            .filter(|t| status_class(&t.status) == wanted)
+            .collect();
+        if tables.is_empty() {
+            continue;
+        }
+
+        html.push_str(&format!(
+            "<h2>{}</h2>\n<table>\n<tr><th>Table</th></tr>\n",
+            title
+        ));
+        for table in tables {
+            html.push_str(&format!(
+                "<tr><td><code>{}</code></td></tr>\n",
+                escape_html(&table.table_name)
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    // Modified tables, with their column and index changes
+    let modified_tables: Vec<_> = comparison
+        .table_differences
+        .iter()
+        .filter(|t| matches!(t.status, DiffStatus::Modified))
+        .collect();
+
+    if !modified_tables.is_empty() {
+        html.push_str("<h2>Modified tables</h2>\n");
+    }
+    for table in modified_tables {
+        html.push_str(&format!(
+            "<h3><code>{}</code></h3>\n",
+            escape_html(&table.table_name)
+        ));
+
+        let column_changes: Vec<_> = table
+            .column_changes
+            .iter()
+            .filter(|c| !matches!(c.status, DiffStatus::Identical))
+            .collect();
+        if !column_changes.is_empty() {
+            html.push_str("<table>\n<tr><th>Column</th><th>Status</th><th>Changes</th></tr>\n");
+            for change in column_changes {
+                html.push_str(&format!(
+                    "<tr><td><code>{}</code></td>{}<td>{}</td></tr>\n",
+                    escape_html(&change.column_name),
+                    status_cell(&change.status),
+                    escape_html(&change.changes.join("; "))
+                ));
+            }
+            html.push_str("</table>\n");
+        }
+
+        let index_changes: Vec<_> = table
+            .index_changes
+            .iter()
+            .filter(|i| !matches!(i.status, DiffStatus::Identical))
+            .collect();
+        if !index_changes.is_empty() {
+            html.push_str("<table>\n<tr><th>Index</th><th>Status</th><th>Definition</th></tr>\n");
+            for change in index_changes {
+                // Show the desired definition, or the existing one for removals
+                let definition = change
+                    .source_definition
+                    .as_ref()
+                    .or(change.target_definition.as_ref())
+                    .map(|i| i.definition.as_str())
+                    .unwrap_or_default();
+                html.push_str(&format!(
+                    "<tr><td><code>{}</code></td>{}<td><code>{}</code></td></tr>\n",
+                    escape_html(&change.index_name),
+                    status_cell(&change.status),
+                    escape_html(definition)
+                ));
+            }
+            html.push_str("</table>\n");
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+    Ok(html)
+}
+
+fn status_class(status: &DiffStatus) -> &'static str {
+    match status {
+        DiffStatus::Added => "added",
+        DiffStatus::Removed => "removed",
+        DiffStatus::Modified => "modified",
+        DiffStatus::Identical => "identical",
+    }
+}
+
+fn status_cell(status: &DiffStatus) -> String {
+    let class = status_class(status);
+    format!("<td class=\"status {}\">{}</td>", class, class)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Render a comparison as CSV with one row per change:
+/// `object_type,object_name,change_type,detail`
+pub fn generate_csv_report(comparison: &SchemaComparison) -> String {
+    let mut rows: Vec<[String; 4]> = Vec::new();
+    let mut push = |object_type: &str, object_name: String, status: &DiffStatus, detail: String| {
+        if !matches!(status, DiffStatus::Identical) {
+            rows.push([
+                object_type.to_string(),
+                object_name,
+                status_class(status).to_string(),
+                detail,
+            ]);
+        }
+    };
+
+    for table in &comparison.table_differences {
+        push(
+            "table",
+            table.table_name.clone(),
+            &table.status,
+            String::new(),
+        );
+
+        // Added and removed tables already imply every column and index
+        if !matches!(table.status, DiffStatus::Modified) {
+            continue;
+        }
+
+        for column in &table.column_changes {
+            let detail = match (&column.status, &column.source_definition) {
+                (DiffStatus::Added, Some(def)) => def.data_type.clone(),
+                _ => column.changes.join("; "),
+            };
+            push(
+                "column",
+                format!("{}.{}", table.table_name, column.column_name),
+                &column.status,
+                detail,
+            );
+        }
+        for index in &table.index_changes {
+            let detail = index
+                .source_definition
+                .as_ref()
+                .or(index.target_definition.as_ref())
+                .map(|i| i.definition.clone())
+                .unwrap_or_default();
+            push("index", index.index_name.clone(), &index.status, detail);
+        }
+        for fk in &table.fk_changes {
+            let detail = fk
+                .source_definition
+                .as_ref()
+                .or(fk.target_definition.as_ref())
+                .map(|f| {
+                    format!(
+                        "{} -> {}.{}",
+                        f.column_name, f.foreign_table_name, f.foreign_column_name
+                    )
+                })
+                .unwrap_or_default();
+            push(
+                "foreign_key",
+                fk.constraint_name.clone(),
+                &fk.status,
+                detail,
+            );
+        }
+        for policy in &table.policy_changes {
+            let detail = policy
+                .source_definition
+                .as_ref()
+                .or(policy.target_definition.as_ref())
+                .map(|p| p.command.clone())
+                .unwrap_or_default();
+            push(
+                "policy",
+                format!("{}.{}", table.table_name, policy.policy_name),
+                &policy.status,
+                detail,
+            );
+        }
+        if let Some(enabled) = table.rls_enabled_change {
+            let detail = if enabled { "enable" } else { "disable" };
+            push(
+                "row_level_security",
+                table.table_name.clone(),
+                &DiffStatus::Modified,
+                detail.to_string(),
+            );
+        }
+    }
+
+    for view in &comparison.view_differences {
+        push("view", view.view_name.clone(), &view.status, String::new());
+    }
+    for routine in &comparison.routine_differences {
+        push(
+            "routine",
+            routine.routine_name.clone(),
+            &routine.status,
+            routine.signature.clone(),
+        );
+    }
+    for domain in &comparison.domain_differences {
+        let detail = domain
+            .source_definition
+            .as_ref()
+            .or(domain.target_definition.as_ref())
+            .map(|d| d.base_type.clone())
+            .unwrap_or_default();
+        push("domain", domain.domain_name.clone(), &domain.status, detail);
+    }
+    for trigger in &comparison.event_trigger_differences {
+        let detail = trigger
+            .source_definition
+            .as_ref()
+            .or(trigger.target_definition.as_ref())
+            .map(|t| t.event.clone())
+            .unwrap_or_default();
+        push(
+            "event_trigger",
+            trigger.trigger_name.clone(),
+            &trigger.status,
+            detail,
+        );
+    }
+
+    let mut csv = String::from("object_type,object_name,change_type,detail\n");
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|field| escape_csv(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+// Quote fields containing separators, quotes or line breaks (RFC 4180)
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Per-object fingerprints keyed by (object type, name). Index, foreign key and
+/// policy names are prefixed with their table as `table.name`.
+pub type SchemaFingerprints = HashMap<(String, String), String>;
+
+/// Count differences from fingerprints alone, for a quick summary of large schemas.
+/// Tables count as modified when their columns, row level security, indexes, foreign
+/// keys or policies differ, the same checks `compare_schemas` makes.
+pub fn summarize_fingerprints(
+    source: &SchemaFingerprints,
+    target: &SchemaFingerprints,
+) -> ComparisonSummary {
+    let mut summary = ComparisonSummary {
+        tables_modified: 0,
+        tables_added: 0,
+        tables_removed: 0,
+        indexes_missing: 0,
+        views_changed: 0,
+        routines_changed: 0,
+        domains_changed: 0,
+        event_triggers_changed: 0,
+    };
+
+    let has_table = |fingerprints: &SchemaFingerprints, table: &str| {
+        fingerprints.contains_key(&("table".to_string(), table.to_string()))
+    };
+    let mut modified_tables: HashSet<&str> = HashSet::new();
+
+    let mut all_keys: HashSet<&(String, String)> = source.keys().collect();
+    all_keys.extend(target.keys());
+
+    for key in all_keys {
+        let (object_type, name) = key;
+        let status = match (source.get(key), target.get(key)) {
+            (Some(src), Some(tgt)) if src == tgt => continue,
+            (Some(_), Some(_)) => DiffStatus::Modified,
+            (Some(_), None) => DiffStatus::Added,
+            (None, _) => DiffStatus::Removed,
+        };
+
+        match object_type.as_str() {
+            "table" => match status {
+                DiffStatus::Added => summary.tables_added += 1,
+                DiffStatus::Removed => summary.tables_removed += 1,
+                _ => {
+                    modified_tables.insert(name);
+                }
+            },
+            "index" | "foreign_key" | "policy" => {
+                let table = name.split_once('.').map(|(t, _)| t).unwrap_or(name);
+                // Objects of added or removed tables are covered by the table itself
+                if has_table(source, table) && has_table(target, table) {
+                    if object_type == "index" && !matches!(status, DiffStatus::Modified) {
+                        summary.indexes_missing += 1;
+                    }
+                    modified_tables.insert(table);
+                }
+            }
+            "view" => summary.views_changed += 1,
+            "routine" => summary.routines_changed += 1,
+            "domain" => summary.domains_changed += 1,
+            "event_trigger" => summary.event_triggers_changed += 1,
+            _ => {}
+        }
+    }
+
+    summary.tables_modified = modified_tables.len();
+    summary
+}
@@ -0,0 +1,185 @@
+use crate::constants::SQL_NULLABLE_YES;
+use crate::models::{EnhancedColumnInfo, EnhancedDatabaseSchema, EnhancedTableInfo};
+use crate::utils::{qualified_name, quote_identifier};
+use std::collections::HashMap;
+
+/// Catalog details information_schema cannot express, fetched by the caller
+/// with names schema-qualified
+#[derive(Default)]
+pub struct SqlExportCatalog {
+    pub column_types: HashMap<(String, String), String>, // (table, column) -> format_type()
+    pub sequences: Vec<String>,                          // CREATE SEQUENCE statements
+    pub sequence_owners: Vec<String>,                    // ALTER SEQUENCE ... OWNED BY statements
+    pub foreign_keys: Vec<(String, String, String)>, // (table, constraint, pg_get_constraintdef())
+    pub function_definitions: Vec<String>,           // complete pg_get_functiondef() outputs
+}
+
+/// Render a full CREATE script for a schema. Objects are emitted in dependency
+/// order: sequences, tables, indexes, foreign keys, functions, then views.
+pub fn render_schema_as_sql(
+    schema_name: &str,
+    schema: &EnhancedDatabaseSchema,
+    catalog: &SqlExportCatalog,
+) -> String {
+    let mut sql = String::new();
+
+    sql.push_str(&format!("-- Schema export: {}\n", schema_name));
+    sql.push_str(&format!(
+        "-- Generated: {}\n\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    sql.push_str(&format!(
+        "CREATE SCHEMA IF NOT EXISTS {};\n",
+        quote_identifier(schema_name)
+    ));
+    // Column defaults and view bodies name objects relative to the schema
+    sql.push_str(&format!(
+        "SET search_path TO {}, public;\n\n",
+        quote_identifier(schema_name)
+    ));
+
+    // Before the tables, whose nextval() defaults need them
+    push_section(&mut sql, "SEQUENCES");
+    for sequence in &catalog.sequences {
+        sql.push_str(&format!("{};\n", sequence));
+    }
+    sql.push('\n');
+
+    push_section(&mut sql, "TABLES");
+    for table in &schema.tables {
+        sql.push_str(&render_table_as_sql(
+            schema_name,
+            table,
+            &catalog.column_types,
+        ));
+    }
+    for owner in &catalog.sequence_owners {
+        sql.push_str(&format!("{};\n", owner));
+    }
+    sql.push('\n');
+
+    push_section(&mut sql, "INDEXES");
+    for table in &schema.tables {
+        // Primary key indexes come with the table's PRIMARY KEY constraint
+        for index in table.indexes.iter().filter(|i| !i.is_primary) {
+            sql.push_str(&format!("{};\n", index.definition));
+        }
+    }
+    sql.push('\n');
+
+    // Added after every table exists so references never point forward
+    push_section(&mut sql, "FOREIGN KEYS");
+    for (table_name, constraint_name, definition) in &catalog.foreign_keys {
+        sql.push_str(&format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} {};\n",
+            qualified_name(schema_name, table_name),
+            quote_identifier(constraint_name),
+            definition
+        ));
+    }
+    sql.push('\n');
+
+    push_section(&mut sql, "FUNCTIONS/PROCEDURES");
+    for definition in &catalog.function_definitions {
+        sql.push_str(&format!("{};\n\n", definition.trim_end()));
+    }
+
+    push_section(&mut sql, "VIEWS");
+    for view in &schema.views {
+        sql.push_str(&format!(
+            "CREATE VIEW {} AS\n{};\n\n",
+            qualified_name(schema_name, &view.view_name),
+            view.definition.trim_end().trim_end_matches(';')
+        ));
+    }
+
+    sql
+}
+
+fn push_section(sql: &mut String, title: &str) {
+    sql.push_str(&format!(
+        "-- ============================================\n\
+         -- {}\n\
+         -- ============================================\n\n",
+        title
+    ));
+}
+
+/// Render a single CREATE TABLE statement with columns and the primary key.
+/// `column_types` may be empty, in which case types come from information_schema.
+pub fn render_table_as_sql(
+    schema_name: &str,
+    table: &EnhancedTableInfo,
+    column_types: &HashMap<(String, String), String>,
+) -> String {
+    let mut columns: Vec<&EnhancedColumnInfo> = table.columns.iter().collect();
+    columns.sort_by_key(|c| c.ordinal_position);
+
+    let mut lines: Vec<String> = columns
+        .iter()
+        .map(|column| {
+            let nullable = if column.is_nullable == SQL_NULLABLE_YES {
+                ""
+            } else {
+                " NOT NULL"
+            };
+            let default = column
+                .column_default
+                .as_ref()
+                .map(|d| format!(" DEFAULT {}", d))
+                .unwrap_or_default();
+            format!(
+                "  {} {}{}{}",
+                quote_identifier(&column.column_name),
+                column_types
+                    .get(&(table.table_name.clone(), column.column_name.clone()))
+                    .cloned()
+                    .unwrap_or_else(|| column_type(column)),
+                default,
+                nullable
+            )
+        })
+        .collect();
+
+    // A table-level constraint also covers composite primary keys
+    let primary_key: Vec<&str> = columns
+        .iter()
+        .filter(|c| c.is_primary_key)
+        .map(|c| c.column_name.as_str())
+        .collect();
+    if !primary_key.is_empty() {
+        lines.push(format!(
+            "  PRIMARY KEY ({})",
+            join_identifiers(primary_key.into_iter())
+        ));
+    }
+
+    format!(
+        "CREATE TABLE {} (\n{}\n);\n\n",
+        qualified_name(schema_name, &table.table_name),
+        lines.join(",\n")
+    )
+}
+
+// information_schema reports lengths and precision separately from the type name, and
+// only "ARRAY" or "USER-DEFINED" for array and user-defined types
+fn column_type(column: &EnhancedColumnInfo) -> String {
+    match (
+        column.data_type.as_str(),
+        column.character_maximum_length,
+        column.numeric_precision,
+        column.numeric_scale,
+    ) {
+        ("character varying" | "character", Some(length), _, _) => {
+            format!("{}({})", column.data_type, length)
+        }
+        ("numeric", _, Some(precision), Some(scale)) => {
+            format!("numeric({}, {})", precision, scale)
+        }
+        _ => column.data_type.clone(),
+    }
+}
+
+fn join_identifiers<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    names.map(quote_identifier).collect::<Vec<_>>().join(", ")
+}
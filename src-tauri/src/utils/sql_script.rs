@@ -0,0 +1,174 @@
+/// Splits a SQL script into statements, dropping comments.
+///
+/// Semicolons inside string literals, quoted identifiers, and dollar-quoted
+/// bodies (`$$ ... $$`, `$fn$ ... $fn$`) do not end a statement.
+pub fn split_sql_script(script: &str) -> Vec<String> {
+    let chars: Vec<char> = script.chars().collect();
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match c {
+            '-' if next == Some('-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if next == Some('*') => {
+                // Block comments nest in PostgreSQL
+                let mut depth = 0;
+                while i < chars.len() {
+                    if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                        depth += 1;
+                        i += 2;
+                    } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        i += 1;
+                    }
+                }
+                current.push(' ');
+                continue;
+            }
+            '\'' | '"' => {
+                // E'...' strings allow backslash escapes
+                let backslash_escapes = c == '\''
+                    && i > 0
+                    && matches!(chars[i - 1], 'E' | 'e')
+                    && !(i > 1 && is_identifier_char(chars[i - 2]));
+                current.push(c);
+                i += 1;
+                while i < chars.len() {
+                    current.push(chars[i]);
+                    if backslash_escapes && chars[i] == '\\' {
+                        if let Some(&escaped) = chars.get(i + 1) {
+                            current.push(escaped);
+                            i += 1;
+                        }
+                    } else if chars[i] == c {
+                        break;
+                    }
+                    i += 1;
+                }
+                i += 1;
+                continue;
+            }
+            '$' if i == 0 || !is_identifier_char(chars[i - 1]) => {
+                if let Some(tag) = dollar_tag(&chars[i..]) {
+                    let tag_len = tag.chars().count();
+                    current.push_str(&tag);
+                    i += tag_len;
+                    while i < chars.len() && !starts_with(&chars[i..], &tag) {
+                        current.push(chars[i]);
+                        i += 1;
+                    }
+                    if i < chars.len() {
+                        current.push_str(&tag);
+                        i += tag_len;
+                    }
+                    continue;
+                }
+                current.push(c);
+            }
+            ';' => {
+                push_statement(&mut statements, &current);
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+        i += 1;
+    }
+
+    push_statement(&mut statements, &current);
+    statements
+}
+
+/// Uppercased keywords and identifiers of a statement from `split_sql_script`, skipping
+/// string literals, quoted identifiers and dollar-quoted bodies.
+pub fn statement_words(statement: &str) -> Vec<String> {
+    let chars: Vec<char> = statement.chars().collect();
+    let mut words = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\'' | '"' => {
+                let backslash_escapes = c == '\''
+                    && i > 0
+                    && matches!(chars[i - 1], 'E' | 'e')
+                    && !(i > 1 && is_identifier_char(chars[i - 2]));
+                i += 1;
+                while i < chars.len() && chars[i] != c {
+                    if backslash_escapes && chars[i] == '\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            '$' if i == 0 || !is_identifier_char(chars[i - 1]) => {
+                if let Some(tag) = dollar_tag(&chars[i..]) {
+                    i += tag.chars().count();
+                    while i < chars.len() && !starts_with(&chars[i..], &tag) {
+                        i += 1;
+                    }
+                    i += tag.chars().count() - 1;
+                }
+            }
+            c if is_identifier_char(c) && !c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (is_identifier_char(chars[i]) || chars[i] == '$') {
+                    i += 1;
+                }
+                // E'...' prefixes belong to the string that follows
+                if chars.get(i) != Some(&'\'') {
+                    words.push(chars[start..i].iter().collect::<String>().to_uppercase());
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    words
+}
+
+fn push_statement(statements: &mut Vec<String>, statement: &str) {
+    let statement = statement.trim();
+    if !statement.is_empty() {
+        statements.push(statement.to_string());
+    }
+}
+
+// Returns the opening tag (e.g. "$$" or "$body$") if `chars` starts with one
+fn dollar_tag(chars: &[char]) -> Option<String> {
+    let end = chars.iter().skip(1).position(|&c| c == '$')? + 1;
+    let name = &chars[1..end];
+
+    // Tags follow identifier rules, which also rules out positional params like $1
+    let valid = match name.first() {
+        None => true,
+        Some(first) => !first.is_ascii_digit() && name.iter().all(|&c| is_identifier_char(c)),
+    };
+
+    valid.then(|| chars[..=end].iter().collect())
+}
+
+fn starts_with(chars: &[char], tag: &str) -> bool {
+    let mut remaining = chars.iter();
+    tag.chars().all(|c| remaining.next() == Some(&c))
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -59,7 +59,7 @@ export function ObjectSelectionTree({
     });
     comparison.routine_differences.forEach((routine) => {
       if (routine.status !== DIFF_STATUS.IDENTICAL) {
-        allChanges.add(`routine:${routine.routine_name}`);
+        allChanges.add(`routine:${routine.signature}`);
       }
     });
     onSelectionChange(allChanges);
@@ -270,14 +270,12 @@ export function ObjectSelectionTree({
 
           {filteredRoutines.map((routine) => (
             <div
-              key={routine.routine_name}
+              key={routine.signature}
               className="ml-4 flex items-center gap-2 p-2 hover:bg-accent/50 rounded transition-colors"
             >
               <Checkbox
-                checked={selectedChanges.has(`routine:${routine.routine_name}`)}
-                onCheckedChange={() =>
-                  toggleSelection(`routine:${routine.routine_name}`)
-                }
+                checked={selectedChanges.has(`routine:${routine.signature}`)}
+                onCheckedChange={() => toggleSelection(`routine:${routine.signature}`)}
                 disabled={routine.status === DIFF_STATUS.IDENTICAL}
               />
               <span className="font-mono text-sm">{routine.routine_name}</span>
@@ -3,6 +3,7 @@ import {
   testPostgresConnection,
   getDatabaseSchema,
   getDatabaseSchemas,
+  getDefaultSchemaForConnection,
   getConnectionPassword,
   setLastConnection,
   getAutoConnectEnabled,
@@ -60,9 +61,11 @@ export function useConnection(): UseConnectionReturn {
       setAvailableSchemas(schemas);
 
       // Load default schema
-      const dbSchema = await getDatabaseSchema(connection, "public");
+      const defaultSchema =
+        connection.schema ?? (await getDefaultSchemaForConnection(connection));
+      const dbSchema = await getDatabaseSchema(connection, defaultSchema);
       setSchema(dbSchema);
-      setSelectedSchema("public");
+      setSelectedSchema(defaultSchema);
 
       return true;
     } catch (error) {
@@ -147,9 +150,11 @@ export function useConnection(): UseConnectionReturn {
         const schemas = await getDatabaseSchemas(connWithPassword);
         setAvailableSchemas(schemas);
 
-        const dbSchema = await getDatabaseSchema(connWithPassword, "public");
+        const defaultSchema =
+          connWithPassword.schema ?? (await getDefaultSchemaForConnection(connWithPassword));
+        const dbSchema = await getDatabaseSchema(connWithPassword, defaultSchema);
         setSchema(dbSchema);
-        setSelectedSchema("public");
+        setSelectedSchema(defaultSchema);
       } catch (error) {
         setStatus(`Auto-connect failed: ${error}`);
         setConnected(false);
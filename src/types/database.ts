@@ -31,6 +31,49 @@ export interface ConnectionConfig {
   username: string;
   password: string;
   readOnly?: boolean;
+  schema?: string; // Schema to open by default
+  poolMode?: 'single' | 'session' | 'transaction' | 'statement'; // Pooler in front of the server
+}
+
+// Payload of the "connection-lost" and "connection-restored" events
+export interface ConnectionHealthPayload {
+  connection_name: string;
+}
+
+export interface ImportSummary {
+  imported: number;
+  skipped: number;
+  messages: string[]; // why entries were skipped or imported without a password
+}
+
+export interface DatabaseInfo {
+  name: string;
+  owner: string;
+  encoding: string;
+  size_bytes: number | null; // null when we lack CONNECT on the database
+  is_template: boolean;
+}
+
+export interface PreparedStatement {
+  name: string;
+  statement: string;
+  parameter_types: string[];
+}
+
+export interface PoolStats {
+  size: number;
+  idle: number;
+  in_use: number;
+  max_size: number;
+  wait_count: number; // commands that found the session busy and had to queue
+}
+
+export interface SslInfo {
+  ssl_in_use: boolean;
+  ssl_version: string | null;
+  ssl_cipher: string | null;
+  ssl_compression: boolean; // always false on PostgreSQL 14+, which removed it
+  client_cert_used: boolean;
 }
 
 // Enhanced schema types for schema comparison feature
@@ -54,6 +97,20 @@ export interface RoutineInfo {
   routine_type: string; // 'FUNCTION' or 'PROCEDURE'
   definition?: string;
   return_type?: string;
+  argument_types: string[];
+  argument_names: string[];
+  volatility?: string; // 'VOLATILE' | 'STABLE' | 'IMMUTABLE'
+  argument_defaults?: ArgumentDefault[];
+}
+
+export interface RlsPolicy {
+  policy_name: string;
+  table_name: string;
+  command: string; // 'ALL', 'SELECT', 'INSERT', 'UPDATE' or 'DELETE'
+  roles: string[];
+  using_expr?: string;
+  check_expr?: string;
+  restrictive: boolean; // AS RESTRICTIVE rather than the default AS PERMISSIVE
 }
 
 export interface EnhancedColumnInfo {
@@ -73,12 +130,184 @@ export interface EnhancedTableInfo {
   columns: EnhancedColumnInfo[];
   foreign_keys: ForeignKeyInfo[];
   indexes: IndexInfo[];
+  rls_enabled: boolean;
+  rls_policies: RlsPolicy[];
+  parent_table?: string;
+  child_tables: string[];
+  storage_options: StorageParam[]; // from pg_class.reloptions
+}
+
+export interface StorageParam {
+  name: string;
+  value: string;
+}
+
+export interface InheritanceRelation {
+  parent_table: string;
+  child_table: string;
+}
+
+export interface DomainInfo {
+  domain_name: string;
+  base_type: string;
+  not_null: boolean;
+  default?: string;
+  constraints: string[]; // e.g., ["CHECK (VALUE > 0)"]
+}
+
+export interface EnumTypeInfo {
+  enum_name: string;
+  values: string[]; // in sort order
+  columns: EnumColumnUsage[]; // table columns of this type, in any schema
+}
+
+export interface EnumColumnUsage {
+  schema_name: string;
+  table_name: string;
+  column_name: string;
+  is_array: boolean; // column type is an array of the enum
+  column_default?: string;
+}
+
+export interface CompositeAttribute {
+  attribute_name: string;
+  data_type: string;
+  ordinal: number;
+}
+
+export interface CompositeTypeInfo {
+  type_name: string;
+  schema_name: string;
+  attributes: CompositeAttribute[];
+}
+
+export interface EventTriggerInfo {
+  trigger_name: string;
+  event: string; // e.g., 'ddl_command_start', 'sql_drop'
+  enabled: string; // 'ORIGIN', 'REPLICA', 'ALWAYS' or 'DISABLED'
+  function_name: string;
+  tags: string[]; // WHEN TAG IN filter, e.g. 'CREATE TABLE'; empty fires for all
 }
 
 export interface EnhancedDatabaseSchema {
   tables: EnhancedTableInfo[];
   views: ViewInfo[];
   routines: RoutineInfo[];
+  domains: DomainInfo[];
+  enums: EnumTypeInfo[];
+  composite_types: CompositeTypeInfo[];
+  event_triggers: EventTriggerInfo[];
+}
+
+export interface DependencyGraph {
+  nodes: string[];
+  edges: [string, string][]; // [dependent, dependency]
+}
+
+export interface ViewDependencies {
+  depends_on_tables: string[];
+  depends_on_views: string[];
+  used_by_views: string[];
+  used_by_routines: string[];
+}
+
+export interface ImpactAnalysis {
+  views_affected: string[];
+  routines_affected: string[];
+  indexes_affected: string[];
+  risk_level: WarningSeverity;
+}
+
+export interface ConstraintInfo {
+  name: string;
+  constraint_type: string; // 'PRIMARY KEY' | 'FOREIGN KEY' | 'UNIQUE' | 'CHECK' | 'EXCLUDE'
+  columns: string[];
+  definition: string;
+  is_deferrable: boolean;
+  is_validated: boolean;
+}
+
+export interface FunctionArg {
+  name: string;
+  type: string;
+  mode: string; // 'IN' | 'OUT' | 'INOUT' | 'VARIADIC' | 'TABLE'
+  default_value: string | null;
+}
+
+export interface ArgumentDefault {
+  position: number; // 1-based, counting OUT arguments
+  argument_name: string;
+  default_expression: string;
+}
+
+export interface SchemaVersion {
+  version: string;
+  description: string;
+  installed_on: string;
+  success: boolean;
+}
+
+export interface SchemaObjectCount {
+  tables: number;
+  views: number;
+  routines: number;
+  indexes: number;
+  sequences: number;
+}
+
+export interface SchemaObject {
+  name: string;
+  object_type: string; // 'table' | 'view' | 'function' | 'procedure' | 'sequence' | 'index'
+  schema: string;
+}
+
+export interface TablespaceAssignment {
+  table_name: string;
+  tablespace: string;
+  size_bytes: number;
+}
+
+export interface TsOptions {
+  use_nullable_union: boolean; // `column: T | null` instead of `column?: T`
+  export_keyword: boolean;
+  name_transform: string; // interface names: 'pascal', 'camel' or 'none'
+}
+
+// Payload of the "schema-change-notification" event
+export interface SchemaChangeNotification {
+  connection_name: string;
+  channel: string;
+  payload: string;
+}
+
+export interface ErAttribute {
+  name: string;
+  type: string;
+  is_pk: boolean;
+  is_fk: boolean;
+  is_nullable: boolean;
+}
+
+export interface ErEntity {
+  name: string;
+  attributes: ErAttribute[];
+}
+
+export interface ErRelationship {
+  from: string;
+  to: string;
+  cardinality: string; // 'many-to-one' or 'one-to-one'
+}
+
+export interface ErDiagram {
+  entities: ErEntity[];
+  relationships: ErRelationship[];
+}
+
+export interface CloneResult {
+  tables_created: number;
+  indexes_created: number; // including those behind primary key and unique constraints
+  foreign_keys_created: number;
 }
 
 // Schema comparison types
@@ -109,12 +338,22 @@ export interface ForeignKeyChange {
   target_definition?: ForeignKeyInfo;
 }
 
+export interface PolicyChange {
+  policy_name: string;
+  status: DiffStatus;
+  source_definition?: RlsPolicy;
+  target_definition?: RlsPolicy;
+}
+
 export interface TableDifference {
   table_name: string;
   status: DiffStatus;
   column_changes: ColumnChange[];
   index_changes: IndexChange[];
   fk_changes: ForeignKeyChange[];
+  policy_changes: PolicyChange[];
+  rls_enabled_change?: boolean;
+  storage_options: StorageParam[]; // only set for added tables
 }
 
 export interface ViewChange {
@@ -127,12 +366,36 @@ export interface ViewChange {
 
 export interface RoutineChange {
   routine_name: string;
+  signature: string; // name and argument types, which tell overloads apart
   status: DiffStatus;
   source_definition?: string;
   target_definition?: string;
   definition_changed: boolean;
 }
 
+export interface DomainChange {
+  domain_name: string;
+  status: DiffStatus;
+  source_definition?: DomainInfo;
+  target_definition?: DomainInfo;
+}
+
+export interface EnumChange {
+  enum_name: string;
+  status: DiffStatus;
+  values_added: string[]; // in source order
+  values_removed: string[];
+  source_definition?: EnumTypeInfo;
+  target_definition?: EnumTypeInfo;
+}
+
+export interface EventTriggerChange {
+  trigger_name: string;
+  status: DiffStatus;
+  source_definition?: EventTriggerInfo;
+  target_definition?: EventTriggerInfo;
+}
+
 export interface ComparisonWarning {
   severity: WarningSeverity;
   warning_type: string; // 'data_loss', 'locking', 'breaking_change', 'info'
@@ -148,6 +411,8 @@ export interface ComparisonSummary {
   indexes_missing: number;
   views_changed: number;
   routines_changed: number;
+  domains_changed: number;
+  event_triggers_changed: number;
 }
 
 export interface SchemaComparison {
@@ -157,5 +422,14 @@ export interface SchemaComparison {
   table_differences: TableDifference[];
   view_differences: ViewChange[];
   routine_differences: RoutineChange[];
+  domain_differences: DomainChange[];
+  enum_differences: EnumChange[];
+  event_trigger_differences: EventTriggerChange[];
   warnings: ComparisonWarning[];
 }
+
+export interface MigrationEstimate {
+  estimated_seconds: number;
+  high_risk_operations: string[];
+  notes: string[];
+}
@@ -13,3 +13,16 @@ export interface GitCommit {
   author: string;
   timestamp: string;
 }
+
+export interface DiffStat {
+  path: string;
+  insertions: number;
+  deletions: number;
+  is_binary: boolean;
+}
+
+export interface GitUserConfig {
+  name: string | null;
+  email: string | null;
+  source: string; // origin of the identity, e.g. "file:/home/me/.gitconfig"
+}
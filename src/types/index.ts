@@ -4,41 +4,128 @@ export type {
   TableInfo,
   DatabaseSchema,
   ConnectionConfig,
+  DatabaseInfo,
+  ImportSummary,
+  PreparedStatement,
+  PoolStats,
+  SslInfo,
   IndexInfo,
   ViewInfo,
   RoutineInfo,
+  RlsPolicy,
+  DomainInfo,
+  EnumTypeInfo,
+  EnumColumnUsage,
+  CompositeAttribute,
+  CompositeTypeInfo,
+  EventTriggerInfo,
   EnhancedColumnInfo,
   EnhancedTableInfo,
+  InheritanceRelation,
   EnhancedDatabaseSchema,
   ForeignKeyInfo,
+  CloneResult,
+  DependencyGraph,
+  ViewDependencies,
+  ImpactAnalysis,
+  SchemaObject,
+  SchemaObjectCount,
+  TablespaceAssignment,
+  TsOptions,
+  StorageParam,
+  ConstraintInfo,
+  FunctionArg,
+  ArgumentDefault,
+  SchemaVersion,
+  SchemaChangeNotification,
+  ConnectionHealthPayload,
+  ErAttribute,
+  ErEntity,
+  ErRelationship,
+  ErDiagram,
   // Schema comparison types
   DiffStatus,
   WarningSeverity,
   ColumnChange,
   IndexChange,
   ForeignKeyChange,
+  PolicyChange,
   TableDifference,
   ViewChange,
   RoutineChange,
+  DomainChange,
+  EnumChange,
+  EventTriggerChange,
   ComparisonWarning,
   ComparisonSummary,
   SchemaComparison,
+  MigrationEstimate,
 } from './database';
 
 // Query types
 export type {
   QueryResult,
+  SchemaQueryResult,
+  ColumnResultInfo,
+  MaskConfig,
+  ExecutionSummary,
+  MigrationError,
+  MigrationApplicationResult,
+  MigrationProgressPayload,
   QueryHistoryEntry,
+  FrequentQuery,
+  TimelineBucket,
   SavedQuery,
+  QueryComparison,
+  AuditEntry,
 } from './query';
 
+// Performance types
+export type {
+  StatStatement,
+  BloatInfo,
+  IndexRecommendation,
+  MissingFkIndex,
+  PerformanceStats,
+  QueryCostEstimate,
+  PlanNode,
+  ColumnHistogram,
+  QuerySuggestion,
+  MaintenanceResult,
+  AnalyzeResult,
+  SequenceValue,
+  UnusedSequence,
+  MatViewRefreshInfo,
+} from './performance';
+
+// Security types
+export type {
+  TableGrant,
+  RolePermissions,
+  PermissionsReport,
+} from './security';
+
+// Server types
+export type {
+  PgSetting,
+  PlannerSettings,
+  WaitEvent,
+  SubscriptionStatus,
+  HbaRule,
+} from './server';
+
 // Git types
 export type {
   GitStatus,
   GitCommit,
+  DiffStat,
+  GitUserConfig,
 } from './git';
 
 // Settings types
 export type {
   RecentProject,
+  AppVersion,
+  ProjectPathChangedPayload,
+  SettingsChangedPayload,
 } from './settings';
@@ -0,0 +1,111 @@
+// Performance and maintenance types
+import type { WarningSeverity } from './database';
+
+export interface StatStatement {
+  query: string;
+  calls: number;
+  total_exec_time_ms: number;
+  mean_exec_time_ms: number;
+  rows: number;
+  shared_blks_hit: number;
+  shared_blks_read: number;
+}
+
+export interface BloatInfo {
+  table_name: string;
+  bloat_ratio: number;
+  wasted_bytes: number;
+  is_index: boolean;
+  object_name: string;
+  needs_vacuum: boolean;
+}
+
+export interface IndexRecommendation {
+  table_name: string;
+  column_name: string;
+  seq_scan_count: number;
+  seq_tup_read: number;
+  recommendation: string;
+}
+
+export interface PerformanceStats {
+  min_ms: number;
+  max_ms: number;
+  avg_ms: number;
+  p50_ms: number;
+  p95_ms: number;
+  samples: number[]; // in execution order
+}
+
+export interface QueryCostEstimate {
+  startup_cost: number;
+  total_cost: number;
+  plan_rows: number;
+  plan_width: number;
+}
+
+export interface PlanNode {
+  node_type: string;
+  startup_cost: number;
+  total_cost: number;
+  plan_rows: number;
+  actual_rows: number | null; // the actual_* fields are only set by EXPLAIN ANALYZE
+  actual_time_ms: number | null;
+  actual_loops: number | null;
+  relation_name: string | null;
+  children: PlanNode[];
+}
+
+export interface ColumnHistogram {
+  bounds: string[]; // text, since pg_stats stores them as anyarray
+  null_fraction: number;
+  avg_width: number;
+  most_common_values: string[];
+  most_common_freqs: number[];
+  correlation: number;
+}
+
+export interface QuerySuggestion {
+  rule: string; // 'select_star' | 'leading_wildcard' | 'missing_limit' | 'not_in_subquery'
+  message: string;
+  severity: WarningSeverity;
+}
+
+export interface MissingFkIndex {
+  table_name: string;
+  column_name: string; // comma-separated for multi-column foreign keys
+  references_table: string;
+  suggested_sql: string;
+}
+
+export interface MaintenanceResult {
+  duration_ms: number;
+  message: string;
+}
+
+export interface AnalyzeResult {
+  tables_analyzed: string[];
+  duration_ms: number;
+  warnings: string[]; // tables the server skipped or failed to analyze
+  notices: string[]; // ANALYZE VERBOSE progress messages
+}
+
+export interface SequenceValue {
+  sequence_name: string;
+  current_value: number; // start value until the first nextval()
+  max_value: number;
+  percent_used: number;
+  at_risk: boolean;
+}
+
+export interface UnusedSequence {
+  sequence_name: string;
+  drop_statement: string;
+}
+
+export interface MatViewRefreshInfo {
+  view_name: string;
+  last_refresh: string | null; // PostgreSQL does not record refresh times
+  is_populated: boolean;
+  definition: string;
+}
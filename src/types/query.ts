@@ -5,6 +5,25 @@ export interface QueryResult {
   execution_time_ms: number;
 }
 
+// Outcome of a query in one schema; exactly one of result and error is set
+export interface SchemaQueryResult {
+  result: QueryResult | null;
+  error: string | null;
+}
+
+// Columns whose values are masked in query results
+export interface MaskConfig {
+  columns: string[]; // Case-insensitive
+  mask_char: string; // Single character
+}
+
+export interface ColumnResultInfo {
+  name: string;
+  type_oid: number;
+  type_name: string;
+  nullable: boolean;
+}
+
 export interface QueryHistoryEntry {
   id: number;
   query: string;
@@ -14,12 +33,69 @@ export interface QueryHistoryEntry {
   executed_at: string;
 }
 
+export interface FrequentQuery {
+  query: string;
+  execution_count: number;
+  avg_execution_ms: number;
+  last_executed: string;
+}
+
+export interface TimelineBucket {
+  bucket_start: string; // UTC, e.g. "2024-05-01T13:15:00Z"
+  query_count: number;
+  avg_execution_ms: number;
+  total_rows: number;
+}
+
 export interface SavedQuery {
   id: number;
   name: string;
   query: string;
   description: string | null;
   is_pinned: boolean;
+  sort_order: number;
   created_at: string;
   updated_at: string;
 }
+
+export interface ExecutionSummary {
+  statements_executed: number;
+  rows_affected: number;
+  duration_ms: number;
+}
+
+export interface MigrationError {
+  statement_index: number; // 0-based position in the script
+  statement: string;
+  message: string;
+}
+
+export interface MigrationApplicationResult {
+  statements_executed: number;
+  statements_skipped: number; // not run because an earlier statement failed
+  errors: MigrationError[];
+  duration_ms: number;
+}
+
+// Payload of the "migration-progress" event, emitted after each statement
+export interface MigrationProgressPayload {
+  statement_index: number;
+  total_statements: number;
+  success: boolean;
+}
+
+export interface AuditEntry {
+  timestamp: string; // ISO timestamp
+  connection_name: string;
+  username: string;
+  query: string; // Truncated to 1000 characters
+  row_count: number;
+}
+
+export interface QueryComparison {
+  rows_only_in_source: unknown[][];
+  rows_only_in_target: unknown[][];
+  columns_match: boolean;
+  row_count_source: number;
+  row_count_target: number;
+}
@@ -0,0 +1,26 @@
+// Roles, privileges, and access control types
+
+export interface TableGrant {
+  table_schema: string;
+  table_name: string;
+  privilege_type: string;
+  is_grantable: boolean;
+}
+
+export interface RolePermissions {
+  table_grants: TableGrant[];
+  schema_grants: string[]; // e.g. "public: USAGE"
+  is_superuser: boolean;
+  can_login: boolean;
+  connection_limit: number | null;
+}
+
+export interface PermissionsReport {
+  can_select: boolean;
+  can_insert: boolean;
+  can_update: boolean;
+  can_delete: boolean;
+  can_create_table: boolean;
+  is_superuser: boolean;
+  schemas_accessible: string[];
+}
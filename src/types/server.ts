@@ -0,0 +1,47 @@
+// Server configuration types
+
+export interface PgSetting {
+  name: string;
+  setting: string;
+  unit?: string;
+  category: string;
+  short_desc: string;
+  source: string;
+  min_val?: string;
+  max_val?: string;
+}
+
+export interface PlannerSettings {
+  seq_page_cost: number;
+  random_page_cost: number;
+  cpu_tuple_cost: number;
+  cpu_index_tuple_cost: number;
+  effective_cache_size: string; // with unit, e.g. "4GB"
+  parallel_setup_cost: number;
+  recommendation: string | null;
+}
+
+export interface WaitEvent {
+  pid: number;
+  wait_event_type: string;
+  wait_event: string;
+  state: string;
+  query: string | null;
+  blocking_pids: number[]; // empty unless blocked on a lock
+}
+
+export interface SubscriptionStatus {
+  subname: string;
+  subenabled: boolean;
+  received_lsn: string; // empty while the apply worker is not running
+  latest_end_lsn: string;
+  lag_bytes: number;
+}
+
+export interface HbaRule {
+  type: string; // 'local' | 'host' | 'hostssl' | ...
+  database: string; // comma-separated
+  user_name: string; // comma-separated
+  address: string | null; // null for local sockets
+  auth_method: string;
+}
@@ -5,3 +5,19 @@ export interface RecentProject {
   last_accessed: string;
   name?: string;
 }
+
+export interface AppVersion {
+  version: string;
+  build_date: string | null; // YYYY-MM-DD
+}
+
+// Payload of the "project-path-changed" event
+export interface ProjectPathChangedPayload {
+  new_path: string;
+  previous_path: string | null;
+}
+
+// Payload of the "settings-changed" event
+export interface SettingsChangedPayload {
+  key: string; // e.g., 'vim_mode_enabled', 'connections'
+}
@@ -2,14 +2,75 @@ import { invoke } from "@tauri-apps/api/core";
 import type {
   ConnectionConfig,
   DatabaseSchema,
+  TableInfo,
+  DatabaseInfo,
+  ImportSummary,
+  PreparedStatement,
+  PoolStats,
+  SslInfo,
   EnhancedDatabaseSchema,
+  DomainInfo,
+  CompositeTypeInfo,
+  RoutineInfo,
+  EventTriggerInfo,
+  InheritanceRelation,
+  DependencyGraph,
+  ViewDependencies,
+  ImpactAnalysis,
+  SchemaObject,
+  SchemaObjectCount,
+  TablespaceAssignment,
+  TsOptions,
+  StorageParam,
+  ConstraintInfo,
+  FunctionArg,
+  ArgumentDefault,
+  SchemaVersion,
+  ErDiagram,
+  CloneResult,
   QueryResult,
+  SchemaQueryResult,
+  ColumnResultInfo,
+  MaskConfig,
+  ExecutionSummary,
+  MigrationApplicationResult,
   QueryHistoryEntry,
+  FrequentQuery,
+  TimelineBucket,
   SavedQuery,
+  QueryComparison,
+  AuditEntry,
   GitStatus,
   GitCommit,
+  DiffStat,
+  GitUserConfig,
   RecentProject,
+  AppVersion,
   SchemaComparison,
+  ComparisonSummary,
+  MigrationEstimate,
+  StatStatement,
+  BloatInfo,
+  IndexRecommendation,
+  MissingFkIndex,
+  PerformanceStats,
+  QueryCostEstimate,
+  PlanNode,
+  ColumnHistogram,
+  QuerySuggestion,
+  MaintenanceResult,
+  AnalyzeResult,
+  SequenceValue,
+  UnusedSequence,
+  MatViewRefreshInfo,
+  RolePermissions,
+  PermissionsReport,
+  RlsPolicy,
+  PgSetting,
+  PlannerSettings,
+  WaitEvent,
+  SubscriptionStatus,
+  HbaRule,
 } from "../types";
 
 // Connection Management
@@ -19,6 +80,16 @@ export async function testPostgresConnection(
   return await invoke<string>("test_postgres_connection", { config });
 }
 
+export async function getConnectionStringPreview(
+  config: ConnectionConfig
+): Promise<string> {
+  return await invoke<string>("get_connection_string_preview", { config });
+}
+
+export async function getConnectionFromEnv(): Promise<ConnectionConfig> {
+  return await invoke<ConnectionConfig>("get_connection_from_env");
+}
+
 export async function getDatabaseSchema(
   config: ConnectionConfig,
   schema?: string
@@ -26,12 +97,28 @@ export async function getDatabaseSchema(
   return await invoke<DatabaseSchema>("get_database_schema", { config, schema });
 }
 
+export async function getSystemCatalogTables(config: ConnectionConfig): Promise<TableInfo[]> {
+  return await invoke<TableInfo[]>("get_system_catalog_tables", { config });
+}
+
 export async function getDatabaseSchemas(
   config: ConnectionConfig
 ): Promise<string[]> {
   return await invoke<string[]>("get_database_schemas", { config });
 }
 
+export async function getDefaultSchemaForConnection(
+  config: ConnectionConfig
+): Promise<string> {
+  return await invoke<string>("get_default_schema_for_connection", { config });
+}
+
+export async function getDatabaseList(
+  config: ConnectionConfig
+): Promise<DatabaseInfo[]> {
+  return await invoke<DatabaseInfo[]>("get_database_list", { config });
+}
+
 export async function getEnhancedDatabaseSchema(
   config: ConnectionConfig,
   schema?: string
@@ -39,6 +126,229 @@ export async function getEnhancedDatabaseSchema(
   return await invoke<EnhancedDatabaseSchema>("get_enhanced_database_schema", { config, schema });
 }
 
+export async function cloneSchema(
+  config: ConnectionConfig,
+  sourceSchema: string,
+  targetSchema: string,
+  includeData: boolean = false
+): Promise<CloneResult> {
+  return await invoke<CloneResult>("clone_schema", {
+    config,
+    sourceSchema,
+    targetSchema,
+    includeData,
+  });
+}
+
+export async function importSqlFileAndExecute(
+  config: ConnectionConfig,
+  filePath: string,
+  transactional: boolean = true
+): Promise<ExecutionSummary> {
+  return await invoke<ExecutionSummary>("import_sql_file_and_execute", {
+    config,
+    filePath,
+    transactional,
+  });
+}
+
+export async function applyMigrationScript(
+  config: ConnectionConfig,
+  script: string,
+  dryRun: boolean = true
+): Promise<MigrationApplicationResult> {
+  return await invoke<MigrationApplicationResult>("apply_migration_script", {
+    config,
+    script,
+    dryRun,
+  });
+}
+
+export async function getColumnDataTypeMap(
+  config: ConnectionConfig
+): Promise<Record<number, string>> {
+  return await invoke<Record<number, string>>("get_column_data_type_map", { config });
+}
+
+export async function getDomainTypes(
+  config: ConnectionConfig,
+  schema: string
+): Promise<DomainInfo[]> {
+  return await invoke<DomainInfo[]>("get_domain_types", { config, schema });
+}
+
+export async function getCompositeTypes(
+  config: ConnectionConfig,
+  schema: string
+): Promise<CompositeTypeInfo[]> {
+  return await invoke<CompositeTypeInfo[]>("get_composite_types", { config, schema });
+}
+
+export async function getFunctionSignatures(
+  config: ConnectionConfig,
+  schema: string
+): Promise<RoutineInfo[]> {
+  return await invoke<RoutineInfo[]>("get_function_signatures", { config, schema });
+}
+
+export async function getStoredProcedureSource(
+  config: ConnectionConfig,
+  schema: string,
+  routineName: string
+): Promise<string> {
+  return await invoke<string>("get_stored_procedure_source", {
+    config,
+    schema,
+    routineName,
+  });
+}
+
+export async function getIndexDefinition(
+  config: ConnectionConfig,
+  schema: string,
+  indexName: string
+): Promise<string> {
+  return await invoke<string>("get_index_definition", { config, schema, indexName });
+}
+
+export async function getConstraintDefinition(
+  config: ConnectionConfig,
+  schema: string,
+  constraintName: string
+): Promise<string> {
+  return await invoke<string>("get_constraint_definition", { config, schema, constraintName });
+}
+
+export async function getPlpgsqlFunctionArgs(
+  config: ConnectionConfig,
+  schema: string,
+  functionName: string
+): Promise<FunctionArg[]> {
+  return await invoke<FunctionArg[]>("get_plpgsql_function_args", {
+    config,
+    schema,
+    functionName,
+  });
+}
+
+export async function getRoutineArgumentDefaults(
+  config: ConnectionConfig,
+  schema: string,
+  routineName: string
+): Promise<ArgumentDefault[]> {
+  return await invoke<ArgumentDefault[]>("get_routine_argument_defaults", {
+    config,
+    schema,
+    routineName,
+  });
+}
+
+export async function getEventTriggers(
+  config: ConnectionConfig
+): Promise<EventTriggerInfo[]> {
+  return await invoke<EventTriggerInfo[]>("get_event_triggers", { config });
+}
+
+export async function getInheritanceHierarchy(
+  config: ConnectionConfig,
+  schema: string
+): Promise<InheritanceRelation[]> {
+  return await invoke<InheritanceRelation[]>("get_inheritance_hierarchy", { config, schema });
+}
+
+export async function getCteDependencyGraph(
+  config: ConnectionConfig,
+  schema: string,
+  viewName: string
+): Promise<DependencyGraph> {
+  return await invoke<DependencyGraph>("get_cte_dependency_graph", {
+    config,
+    schema,
+    viewName,
+  });
+}
+
+export async function getViewDependencies(
+  config: ConnectionConfig,
+  schema: string,
+  viewName: string
+): Promise<ViewDependencies> {
+  return await invoke<ViewDependencies>("get_view_dependencies", {
+    config,
+    schema,
+    viewName,
+  });
+}
+
+export async function getSchemaChangeImpactAnalysis(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string,
+  columnName: string
+): Promise<ImpactAnalysis> {
+  return await invoke<ImpactAnalysis>("get_schema_change_impact_analysis", {
+    config,
+    schema,
+    tableName,
+    columnName,
+  });
+}
+
+export async function getSchemaObjectCount(
+  config: ConnectionConfig,
+  schema: string
+): Promise<SchemaObjectCount> {
+  return await invoke<SchemaObjectCount>("get_schema_object_count", { config, schema });
+}
+
+export async function getAllSchemaObjects(
+  config: ConnectionConfig,
+  schema: string
+): Promise<SchemaObject[]> {
+  return await invoke<SchemaObject[]>("get_all_schema_objects", { config, schema });
+}
+
+export async function getTableConstraintsSummary(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string
+): Promise<ConstraintInfo[]> {
+  return await invoke<ConstraintInfo[]>("get_table_constraints_summary", {
+    config,
+    schema,
+    tableName,
+  });
+}
+
+export async function getStorageParameters(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string
+): Promise<StorageParam[]> {
+  return await invoke<StorageParam[]>("get_storage_parameters", { config, schema, tableName });
+}
+
+export async function getTablespacePerTable(
+  config: ConnectionConfig,
+  schema: string
+): Promise<TablespaceAssignment[]> {
+  return await invoke<TablespaceAssignment[]>("get_tablespace_per_table", { config, schema });
+}
+
+export async function getTableRowEstimates(
+  config: ConnectionConfig,
+  schema: string
+): Promise<Record<string, number>> {
+  return await invoke<Record<string, number>>("get_table_row_estimates", { config, schema });
+}
+
+export async function getSchemaVersion(
+  config: ConnectionConfig,
+  schema: string
+): Promise<SchemaVersion | null> {
+  return await invoke<SchemaVersion | null>("get_schema_version", { config, schema });
+}
+
 export async function compareSchemas(
   sourceConfig: ConnectionConfig,
   targetConfig: ConnectionConfig,
@@ -51,17 +361,439 @@ export async function compareSchemas(
   });
 }
 
+export async function compareSchemasSummary(
+  sourceConfig: ConnectionConfig,
+  targetConfig: ConnectionConfig,
+  schema?: string
+): Promise<ComparisonSummary> {
+  return await invoke<ComparisonSummary>("compare_schemas_summary", {
+    sourceConfig,
+    targetConfig,
+    schema,
+  });
+}
+
 export async function generateMigrationSql(
   comparison: SchemaComparison
 ): Promise<string> {
   return await invoke<string>("generate_migration_sql", { comparison });
 }
 
+export async function saveHtmlReport(
+  comparison: SchemaComparison,
+  filePath: string
+): Promise<void> {
+  await invoke("save_html_report", { comparison, filePath });
+}
+
+export async function saveCsvReport(
+  comparison: SchemaComparison,
+  filePath: string
+): Promise<void> {
+  await invoke("save_csv_report", { comparison, filePath });
+}
+
+export async function estimateMigrationDuration(
+  sourceConfig: ConnectionConfig,
+  targetConfig: ConnectionConfig,
+  comparison: SchemaComparison,
+  schema?: string
+): Promise<MigrationEstimate> {
+  return await invoke<MigrationEstimate>("estimate_migration_duration", {
+    sourceConfig,
+    targetConfig,
+    comparison,
+    schema,
+  });
+}
+
+export async function compareQueryResults(
+  sourceConfig: ConnectionConfig,
+  targetConfig: ConnectionConfig,
+  query: string
+): Promise<QueryComparison> {
+  return await invoke<QueryComparison>("compare_query_results", {
+    sourceConfig,
+    targetConfig,
+    query,
+  });
+}
+
 export async function executeQuery(
+  config: ConnectionConfig,
+  query: string,
+  maskConfig?: MaskConfig
+): Promise<QueryResult> {
+  return await invoke<QueryResult>("execute_query", { config, query, maskConfig });
+}
+
+export async function runQueryInAllSchemas(
+  config: ConnectionConfig,
+  query: string,
+  schemaFilter?: string, // PostgreSQL regular expression, e.g. "^tenant_"
+  maskConfig?: MaskConfig
+): Promise<Record<string, SchemaQueryResult>> {
+  return await invoke<Record<string, SchemaQueryResult>>("run_query_in_all_schemas", {
+    config,
+    query,
+    schemaFilter,
+    maskConfig,
+  });
+}
+
+export async function getQueryResultSchema(
   config: ConnectionConfig,
   query: string
+): Promise<ColumnResultInfo[]> {
+  return await invoke<ColumnResultInfo[]>("get_query_result_schema", { config, query });
+}
+
+export async function getColumnUniqueValues(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string,
+  columnName: string,
+  limit: number = 100
+): Promise<unknown[]> {
+  return await invoke<unknown[]>("get_column_unique_values", {
+    config,
+    schema,
+    tableName,
+    columnName,
+    limit,
+  });
+}
+
+export async function getTableSample(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string,
+  percent: number,
+  maskConfig?: MaskConfig
 ): Promise<QueryResult> {
-  return await invoke<QueryResult>("execute_query", { config, query });
+  return await invoke<QueryResult>("get_table_sample", {
+    config,
+    schema,
+    tableName,
+    percent,
+    maskConfig,
+  });
+}
+
+// Schema Export
+export async function getSchemaAsDot(
+  config: ConnectionConfig,
+  schema: string,
+  layout: "LR" | "TB" = "LR"
+): Promise<string> {
+  return await invoke<string>("get_schema_as_dot", { config, schema, layout });
+}
+
+export async function getSchemaAsMermaid(
+  config: ConnectionConfig,
+  schema: string
+): Promise<string> {
+  return await invoke<string>("get_schema_as_mermaid", { config, schema });
+}
+
+export async function getSchemaAsOpenapiComponents(
+  config: ConnectionConfig,
+  schema: string
+): Promise<Record<string, unknown>> {
+  return await invoke<Record<string, unknown>>("get_schema_as_openapi_components", {
+    config,
+    schema,
+  });
+}
+
+export async function getSchemaAsJsonschema(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string
+): Promise<Record<string, unknown>> {
+  return await invoke<Record<string, unknown>>("get_schema_as_jsonschema", {
+    config,
+    schema,
+    tableName,
+  });
+}
+
+export async function getSchemaAsTypescriptInterfaces(
+  config: ConnectionConfig,
+  schema: string,
+  options: TsOptions
+): Promise<string> {
+  return await invoke<string>("get_schema_as_typescript_interfaces", { config, schema, options });
+}
+
+export async function generateErDiagramJson(
+  config: ConnectionConfig,
+  schema: string
+): Promise<ErDiagram> {
+  return await invoke<ErDiagram>("generate_er_diagram_json", { config, schema });
+}
+
+export async function exportSchemaToSql(
+  config: ConnectionConfig,
+  schema: string
+): Promise<string> {
+  return await invoke<string>("export_schema_to_sql", { config, schema });
+}
+
+export async function getCreateTableDdl(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string
+): Promise<string> {
+  return await invoke<string>("get_create_table_ddl", { config, schema, tableName });
+}
+
+export async function getResultAsTsv(result: QueryResult): Promise<string> {
+  return await invoke<string>("get_result_as_tsv", { result });
+}
+
+export async function getResultAsMarkdownTable(result: QueryResult): Promise<string> {
+  return await invoke<string>("get_result_as_markdown_table", { result });
+}
+
+// Performance
+export async function getPgStatStatements(
+  config: ConnectionConfig,
+  limit: number = 20,
+  orderBy: "total_time" | "mean_time" | "calls" | "rows" = "total_time"
+): Promise<StatStatement[]> {
+  return await invoke<StatStatement[]>("get_pg_stat_statements", {
+    config,
+    limit,
+    orderBy,
+  });
+}
+
+export async function getBloatEstimate(
+  config: ConnectionConfig,
+  schema: string
+): Promise<BloatInfo[]> {
+  return await invoke<BloatInfo[]>("get_bloat_estimate", { config, schema });
+}
+
+export async function getIndexRecommendations(
+  config: ConnectionConfig,
+  schema: string
+): Promise<IndexRecommendation[]> {
+  return await invoke<IndexRecommendation[]>("get_index_recommendations", { config, schema });
+}
+
+export async function findMissingIndexesOnForeignKeys(
+  config: ConnectionConfig,
+  schema: string
+): Promise<MissingFkIndex[]> {
+  return await invoke<MissingFkIndex[]>("find_missing_indexes_on_foreign_keys", {
+    config,
+    schema,
+  });
+}
+
+export async function testQueryPerformance(
+  config: ConnectionConfig,
+  query: string,
+  iterations: number = 5
+): Promise<PerformanceStats> {
+  return await invoke<PerformanceStats>("test_query_performance", {
+    config,
+    query,
+    iterations,
+  });
+}
+
+export async function getQueryCostEstimate(
+  config: ConnectionConfig,
+  query: string
+): Promise<QueryCostEstimate> {
+  return await invoke<QueryCostEstimate>("get_query_cost_estimate", { config, query });
+}
+
+export async function explainAnalyzeStructured(
+  config: ConnectionConfig,
+  query: string,
+  buffers: boolean = false
+): Promise<PlanNode> {
+  return await invoke<PlanNode>("explain_analyze_structured", { config, query, buffers });
+}
+
+export async function getColumnStatisticsHistogram(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string,
+  columnName: string
+): Promise<ColumnHistogram> {
+  return await invoke<ColumnHistogram>("get_column_statistics_histogram", {
+    config,
+    schema,
+    tableName,
+    columnName,
+  });
+}
+
+export async function suggestQueryOptimizations(query: string): Promise<QuerySuggestion[]> {
+  return await invoke<QuerySuggestion[]>("suggest_query_optimizations", { query });
+}
+
+// Maintenance
+export async function runVacuumAnalyze(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string | null,
+  full: boolean = false,
+  confirm: boolean = false
+): Promise<MaintenanceResult> {
+  return await invoke<MaintenanceResult>("run_vacuum_analyze", {
+    config,
+    schema,
+    tableName,
+    full,
+    confirm,
+  });
+}
+
+export async function analyzeTable(
+  config: ConnectionConfig,
+  schema: string,
+  tableName: string | null,
+  verbose: boolean = false
+): Promise<AnalyzeResult> {
+  return await invoke<AnalyzeResult>("analyze_table", {
+    config,
+    schema,
+    tableName,
+    verbose,
+  });
+}
+
+export async function getUnusedSequences(
+  config: ConnectionConfig,
+  schema: string
+): Promise<UnusedSequence[]> {
+  return await invoke<UnusedSequence[]>("get_unused_sequences", { config, schema });
+}
+
+export async function getSequencesCurrentValues(
+  config: ConnectionConfig,
+  schema: string
+): Promise<SequenceValue[]> {
+  return await invoke<SequenceValue[]>("get_sequences_current_values", { config, schema });
+}
+
+export async function getMaterializedViewRefreshInfo(
+  config: ConnectionConfig,
+  schema: string
+): Promise<MatViewRefreshInfo[]> {
+  return await invoke<MatViewRefreshInfo[]>("get_materialized_view_refresh_info", {
+    config,
+    schema,
+  });
+}
+
+export async function refreshMaterializedView(
+  config: ConnectionConfig,
+  schema: string,
+  viewName: string,
+  concurrently: boolean = false
+): Promise<void> {
+  await invoke("refresh_materialized_view", {
+    config,
+    schema,
+    viewName,
+    concurrently,
+  });
+}
+
+// Security
+export async function getRolePermissions(
+  config: ConnectionConfig,
+  roleName: string
+): Promise<RolePermissions> {
+  return await invoke<RolePermissions>("get_role_permissions", { config, roleName });
+}
+
+export async function getRowLevelSecurityPolicies(
+  config: ConnectionConfig,
+  schema: string,
+  tableName?: string
+): Promise<RlsPolicy[]> {
+  return await invoke<RlsPolicy[]>("get_row_level_security_policies", {
+    config,
+    schema,
+    tableName,
+  });
+}
+
+export async function checkConnectionPermissions(
+  config: ConnectionConfig
+): Promise<PermissionsReport> {
+  return await invoke<PermissionsReport>("check_connection_permissions", { config });
+}
+
+// Server
+export async function getPgSettings(
+  config: ConnectionConfig,
+  filter?: string // LIKE pattern, e.g. "%work_mem%"
+): Promise<PgSetting[]> {
+  return await invoke<PgSetting[]>("get_pg_settings", { config, filter });
+}
+
+export async function getPlannerSettings(config: ConnectionConfig): Promise<PlannerSettings> {
+  return await invoke<PlannerSettings>("get_planner_settings", { config });
+}
+
+export async function getPgWaitEvents(config: ConnectionConfig): Promise<WaitEvent[]> {
+  return await invoke<WaitEvent[]>("get_pg_wait_events", { config });
+}
+
+export async function getPublicationTables(
+  config: ConnectionConfig,
+  publicationName: string
+): Promise<string[]> {
+  return await invoke<string[]>("get_publication_tables", { config, publicationName });
+}
+
+export async function getSubscriptionStatus(
+  config: ConnectionConfig
+): Promise<SubscriptionStatus[]> {
+  return await invoke<SubscriptionStatus[]>("get_subscription_status", { config });
+}
+
+export async function getPgHbaRules(config: ConnectionConfig): Promise<HbaRule[]> {
+  return await invoke<HbaRule[]>("get_pg_hba_rules", { config });
+}
+
+// Session
+export async function getPreparedStatements(
+  config: ConnectionConfig
+): Promise<PreparedStatement[]> {
+  return await invoke<PreparedStatement[]>("get_prepared_statements", { config });
+}
+
+export async function getConnectionPoolStats(connectionName: string): Promise<PoolStats> {
+  return await invoke<PoolStats>("get_connection_pool_stats", { connectionName });
+}
+
+export async function getConnectionSslInfo(config: ConnectionConfig): Promise<SslInfo> {
+  return await invoke<SslInfo>("get_connection_ssl_info", { config });
+}
+
+// Notifications arrive as "schema-change-notification" events
+export async function watchSchemaChanges(
+  config: ConnectionConfig,
+  channel: string
+): Promise<void> {
+  await invoke("watch_schema_changes", { config, channel });
+}
+
+export async function unwatchSchemaChanges(
+  config: ConnectionConfig,
+  channel: string
+): Promise<void> {
+  await invoke("unwatch_schema_changes", { config, channel });
 }
 
 // Connection Storage
@@ -96,6 +828,18 @@ export async function deleteConnectionPassword(
   await invoke("delete_connection_password", { name: connectionName });
 }
 
+export async function importConnectionsFromPgpass(
+  filePath: string
+): Promise<ImportSummary> {
+  return await invoke<ImportSummary>("import_connections_from_pgpass", { filePath });
+}
+
+export async function importConnectionsFromDbeaver(
+  filePath: string
+): Promise<ImportSummary> {
+  return await invoke<ImportSummary>("import_connections_from_dbeaver", { filePath });
+}
+
 // Query History
 export async function saveQueryToHistory(
   query: string,
@@ -121,6 +865,48 @@ export async function clearQueryHistory(): Promise<void> {
   await invoke("clear_query_history");
 }
 
+export async function getQueryHistoryByConnection(): Promise<
+  Record<string, QueryHistoryEntry[]>
+> {
+  return await invoke<Record<string, QueryHistoryEntry[]>>("get_query_history_by_connection");
+}
+
+export async function clearHistoryForConnection(connectionName: string): Promise<number> {
+  return await invoke<number>("clear_history_for_connection", { connectionName });
+}
+
+export async function getHistoryEntryById(id: number): Promise<QueryHistoryEntry> {
+  return await invoke<QueryHistoryEntry>("get_history_entry_by_id", { id });
+}
+
+export async function deduplicateQueryHistory(): Promise<number> {
+  return await invoke<number>("deduplicate_query_history");
+}
+
+export async function getSlowestQueries(
+  limit: number = 10,
+  connectionName: string | null = null
+): Promise<QueryHistoryEntry[]> {
+  return await invoke<QueryHistoryEntry[]>("get_slowest_queries", { limit, connectionName });
+}
+
+export async function getMostFrequentQueries(
+  limit: number = 10,
+  connectionName: string | null = null
+): Promise<FrequentQuery[]> {
+  return await invoke<FrequentQuery[]>("get_most_frequent_queries", { limit, connectionName });
+}
+
+export async function getQueryHistoryTimeline(
+  bucketMinutes: number,
+  limitBuckets: number
+): Promise<TimelineBucket[]> {
+  return await invoke<TimelineBucket[]>("get_query_history_timeline", {
+    bucketMinutes,
+    limitBuckets,
+  });
+}
+
 // Saved Queries
 export async function saveQuery(
   name: string,
@@ -142,6 +928,40 @@ export async function togglePinQuery(id: number): Promise<void> {
   await invoke("toggle_pin_query", { id });
 }
 
+export async function renameSavedQuery(id: number, newName: string): Promise<void> {
+  await invoke("rename_saved_query", { id, newName });
+}
+
+export async function getSavedQueryById(id: number): Promise<SavedQuery> {
+  return await invoke<SavedQuery>("get_saved_query_by_id", { id });
+}
+
+export async function reorderSavedQueries(orderedIds: number[]): Promise<void> {
+  await invoke("reorder_saved_queries", { orderedIds });
+}
+
+export async function saveQueryFromHistory(
+  historyId: number,
+  name: string,
+  description: string | null
+): Promise<SavedQuery> {
+  return await invoke<SavedQuery>("save_query_from_history", {
+    historyId,
+    name,
+    description,
+  });
+}
+
+// App
+export async function getAppVersion(): Promise<AppVersion> {
+  return await invoke<AppVersion>("get_app_version");
+}
+
+// Audit
+export async function getAuditLog(limit: number): Promise<AuditEntry[]> {
+  return await invoke<AuditEntry[]>("get_audit_log", { limit });
+}
+
 // Settings/Project
 export async function loadProjectSettings(): Promise<void> {
   await invoke("load_project_settings");
@@ -183,6 +1003,23 @@ export async function setVimModeEnabled(enabled: boolean): Promise<void> {
   await invoke("set_vim_mode_enabled", { enabled });
 }
 
+export async function getHistoryRetentionDays(): Promise<number | null> {
+  return await invoke<number | null>("get_history_retention_days");
+}
+
+// Pass null to keep history forever
+export async function setHistoryRetentionDays(days: number | null): Promise<void> {
+  await invoke("set_history_retention_days", { days });
+}
+
+export async function getHealthCheckIntervalSecs(): Promise<number | null> {
+  return await invoke<number | null>("get_health_check_interval_secs");
+}
+
+export async function setHealthCheckIntervalSecs(secs: number): Promise<void> {
+  await invoke("set_health_check_interval_secs", { secs });
+}
+
 export async function getLastConnection(): Promise<string | null> {
   return await invoke<string | null>("get_last_connection");
 }
@@ -219,3 +1056,46 @@ export async function gitPush(): Promise<string> {
 export async function gitPull(): Promise<string> {
   return await invoke<string>("git_pull");
 }
+
+export async function gitCherryPick(commitHash: string): Promise<string> {
+  return await invoke<string>("git_cherry_pick", { commitHash });
+}
+
+export async function gitReset(
+  mode: "soft" | "mixed" | "hard",
+  commit: string,
+  confirm: boolean = false
+): Promise<string> {
+  return await invoke<string>("git_reset", { mode, commit, confirm });
+}
+
+export async function gitSetUserConfig(
+  name: string,
+  email: string,
+  global: boolean = false
+): Promise<void> {
+  await invoke("git_set_user_config", { name, email, global });
+}
+
+export async function gitGetUserConfig(): Promise<GitUserConfig> {
+  return await invoke<GitUserConfig>("git_get_user_config");
+}
+
+export async function gitGetDiffStat(
+  commit1?: string,
+  commit2?: string
+): Promise<DiffStat[]> {
+  return await invoke<DiffStat[]>("git_get_diff_stat", { commit1, commit2 });
+}
+
+export async function gitGetUntrackedFiles(): Promise<string[]> {
+  return await invoke<string[]>("git_get_untracked_files");
+}
+
+export async function gitAddFile(relativePath: string): Promise<void> {
+  await invoke("git_add_file", { relativePath });
+}
+
+export async function gitAddAll(): Promise<void> {
+  await invoke("git_add_all");
+}